@@ -1,6 +1,24 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{FnArg, ItemFn, Pat, ReturnType, parse_macro_input};
+use syn::{FnArg, GenericArgument, ItemFn, Pat, PathArguments, ReturnType, Type, parse_macro_input};
+
+/// If `ty` is (possibly path-qualified) `TaskHandle<T>`, returns `T`.
+fn task_handle_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "TaskHandle" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    })
+}
 
 /// A macro to transform a function into a runnable task.
 ///
@@ -28,12 +46,20 @@ use syn::{FnArg, ItemFn, Pat, ReturnType, parse_macro_input};
 /// The function's parameters are captured and moved into an anonymous struct
 /// that implements the `Task` trait, which is then submitted to the runtime.
 ///
+/// A parameter typed `TaskHandle<T>` is treated as a dependency on another
+/// task: it is awaited and unwrapped to `T` before the function body runs,
+/// so pipelines like `rollout -> advantage -> update` can be expressed by
+/// taking the upstream task's handle as a parameter.
+///
 /// # Panics
 ///
 /// This macro will cause a compile-time panic if:
 /// * The function has no return type.
 /// * The function has a `self` parameter (e.g., `&self` or `self`).
 /// * The function's parameters are not simple identifiers (e.g., `(a, b)`).
+///
+/// It will also panic at runtime if a `TaskHandle<T>` dependency resolves to
+/// an error (the upstream task failed or was canceled).
 #[proc_macro_attribute]
 pub fn task(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let input_fn = parse_macro_input!(item as ItemFn);
@@ -55,10 +81,16 @@ pub fn task(_attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
-    // Extract parameters for the new function signature and task struct
+    // Extract parameters for the new function signature and task struct.
+    // A parameter typed `TaskHandle<T>` is treated as a dependency: the
+    // generated task stores the handle and awaits/unwraps it to `T` before
+    // the function body runs, so users can express pipelines like
+    // `rollout -> advantage -> update` by taking upstream `TaskHandle`s as
+    // parameters.
     let mut param_names = Vec::new();
     let mut param_types = Vec::new();
     let mut fn_params = Vec::new();
+    let mut dependency_names = Vec::new();
 
     for input in &input_fn.sig.inputs {
         match input {
@@ -75,6 +107,10 @@ pub fn task(_attr: TokenStream, item: TokenStream) -> TokenStream {
                     let param_name = &pat_ident.ident;
                     let param_type = &pat_type.ty;
 
+                    if task_handle_inner_type(param_type).is_some() {
+                        dependency_names.push(param_name.clone());
+                    }
+
                     param_names.push(param_name);
                     param_types.push(param_type);
                     fn_params.push(quote! { #param_name: #param_type });
@@ -124,7 +160,14 @@ pub fn task(_attr: TokenStream, item: TokenStream) -> TokenStream {
                     Box<dyn ::std::future::Future<Output = #output_type> + Send>
                 > {
                     #(let #param_names = self.#param_names;)*
-                    Box::pin(async move #fn_body)
+                    Box::pin(async move {
+                        #(
+                            let #dependency_names = #dependency_names
+                                .await
+                                .expect("ferrum task dependency failed");
+                        )*
+                        #fn_body
+                    })
                 }
             }
 
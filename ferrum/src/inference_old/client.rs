@@ -1,12 +1,42 @@
 use super::engine::InferenceEngine;
+use super::metrics::ClientMetrics;
 use super::types::{
-    InferenceEngineInput, InferenceEngineOutput, InferenceError, Message, NamedWeightUpdateRequest,
-    SamplingParams,
+    InferenceEngineInput, InferenceEngineOutput, InferenceError, InferenceErrorContext, Message,
+    NamedWeightUpdateRequest, RetryPolicy, SamplingParams, StopReason,
 };
 use async_trait::async_trait;
 use futures::future::join_all;
-use std::collections::HashMap;
+use futures::stream::{FuturesUnordered, Stream, StreamExt};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+// Virtual nodes inserted into the hash ring per unit of an engine's
+// `tp_size`. An engine with a larger `tp_size` can serve proportionally more
+// traffic, so it gets proportionally more virtual nodes, i.e. a
+// proportionally larger share of the ring's keyspace.
+const RING_VNODES_PER_TP_UNIT: usize = 100;
+
+/// One unit of streamed `generate_stream` output: the trajectory's position
+/// in the original request, an incremental delta, and — once that
+/// trajectory is done — its terminal stop reason.
+///
+/// Token-level deltas would need `InferenceEngine::generate` itself to
+/// stream, which it doesn't today; each chunk here carries one trajectory
+/// group's full response as a single delta. What *is* streamed is engine
+/// groups completing: chunks for a fast group are yielded as soon as that
+/// group's `call_with_retry_and_reroute` resolves, instead of every
+/// trajectory waiting on the slowest group in the batch.
+#[derive(Debug, Clone)]
+pub struct StreamChunk {
+    pub original_index: usize,
+    pub delta: String,
+    pub stop_reason: Option<StopReason>,
+    // Whether this slot was ultimately served by a fallback engine after
+    // the originally-routed one failed; mirrors `InferenceEngineOutput::used_fallback`.
+    pub used_fallback: bool,
+}
 
 #[derive(Debug, Clone)]
 struct TrajectoryItem {
@@ -23,6 +53,25 @@ enum TrajectoryData {
 
 pub struct InferenceEngineClient {
     engines: Vec<Box<dyn InferenceEngine>>,
+    retry_policy: RetryPolicy,
+    // Consistent-hashing ring mapping ring positions to engine indices,
+    // weighted by each engine's `tp_size` so larger engines carry
+    // proportionally more traffic. Built once at construction time; adding or
+    // removing one engine only remaps ~1/N of trajectories instead of
+    // invalidating every engine's prefix cache the way plain modulo does.
+    ring: BTreeMap<u64, usize>,
+    // Request/in-flight/latency/token/retry/error counters, exported via
+    // `metrics_handle`. A no-op unless built with the `metrics` feature.
+    metrics: ClientMetrics,
+    // Last `weight_version` accepted by `update_named_weight`, reset to 0 by
+    // `init_weight_update_communicator`. Lets us reject a stale or
+    // out-of-order weight update before it can leave tp-sharded replicas on
+    // different policy snapshots. The monotonicity guarantee is scoped to a
+    // single communicator generation, not the client's whole lifetime: a
+    // re-init starts a new distributed process group, so the next sequence
+    // is free to start low again rather than needing to exceed whatever a
+    // prior (torn-down) communicator last saw.
+    weight_version: AtomicU64,
 }
 
 impl InferenceEngineClient {
@@ -33,17 +82,92 @@ impl InferenceEngineClient {
             ));
         }
 
-        println!(
-            "InferenceEngineClient initialized with {} engines.",
-            engines.len()
-        );
-        Ok(Self { engines })
+        tracing::info!(num_engines = engines.len(), "InferenceEngineClient initialized");
+        let ring = Self::build_ring(&engines);
+        Ok(Self {
+            engines,
+            retry_policy: RetryPolicy::default(),
+            ring,
+            metrics: ClientMetrics::new(),
+            weight_version: AtomicU64::new(0),
+        })
+    }
+
+    /// Scrape every collector registered for this client in Prometheus text
+    /// exposition format, for an operator's `/metrics` endpoint to serve
+    /// directly. Empty unless built with the `metrics` feature.
+    pub fn metrics_handle(&self) -> String {
+        self.metrics.scrape()
+    }
+
+    /// Override the retry/backoff policy applied to per-engine calls made on
+    /// behalf of `generate`. Does not affect weight-update calls
+    /// (`update_named_weight`, `init_weight_update_communicator`), which stay
+    /// all-or-nothing regardless of this policy — see [`RetryPolicy`].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Build a consistent-hashing ring with `tp_size * RING_VNODES_PER_TP_UNIT`
+    /// virtual nodes per engine, so an engine's share of the keyspace (and
+    /// thus of routed trajectories) scales with how much traffic it can
+    /// actually serve. Call this again (and replace `self.ring`) whenever the
+    /// live engine set changes.
+    fn build_ring(engines: &[Box<dyn InferenceEngine>]) -> BTreeMap<u64, usize> {
+        let mut ring = BTreeMap::new();
+        for (engine_idx, engine) in engines.iter().enumerate() {
+            let vnodes = engine.tp_size() * RING_VNODES_PER_TP_UNIT;
+            for vnode in 0..vnodes {
+                let mut hasher = DefaultHasher::new();
+                format!("{engine_idx}#{vnode}").hash(&mut hasher);
+                ring.insert(hasher.finish(), engine_idx);
+            }
+        }
+        ring
     }
 
     fn calculate_engine_index(&self, trajectory_id: &str) -> usize {
+        if self.ring.is_empty() {
+            return 0;
+        }
+
         let mut hasher = DefaultHasher::new();
         trajectory_id.hash(&mut hasher);
-        (hasher.finish() as usize) % self.engines.len()
+        let key = hasher.finish();
+
+        match self.ring.range(key..).next() {
+            Some((_, &engine_idx)) => engine_idx,
+            // Wrap around: the key is past the last ring entry, so it belongs
+            // to the first one.
+            None => *self.ring.values().next().unwrap(),
+        }
+    }
+
+    /// Recompute a fallback engine via the same ring `calculate_engine_index`
+    /// routes through, instead of a blind `(current_idx + 1) % len`: walk the
+    /// ring starting just past `current_idx`'s own vnodes and take the first
+    /// distinct engine not already in `tried`, wrapping around the ring once.
+    /// Falls back to plain modulo if the ring is empty or every ring entry
+    /// belongs to an already-tried engine.
+    fn next_engine_in_ring(&self, current_idx: usize, tried: &HashSet<usize>) -> usize {
+        if self.ring.is_empty() {
+            return (current_idx + 1) % self.engines.len().max(1);
+        }
+
+        let start_key = self
+            .ring
+            .iter()
+            .find(|&(_, &idx)| idx == current_idx)
+            .map(|(&key, _)| key)
+            .unwrap_or(0);
+
+        self.ring
+            .range((std::ops::Bound::Excluded(start_key), std::ops::Bound::Unbounded))
+            .chain(self.ring.iter())
+            .map(|(_, &idx)| idx)
+            .find(|idx| !tried.contains(idx))
+            .unwrap_or((current_idx + 1) % self.engines.len())
     }
 
     async fn generate_with_trajectory_routing(
@@ -67,6 +191,76 @@ impl InferenceEngineClient {
         Ok(output)
     }
 
+    /// Like `generate_with_trajectory_routing`, but multiplexes each engine
+    /// group's `call_with_retry_and_reroute` future into a single merged
+    /// stream instead of waiting on every group before returning anything: a
+    /// trajectory's `StreamChunk` is yielded as soon as the group handling
+    /// it resolves. `original_index` on each chunk lines up with the index
+    /// `reconstruct_by_original_index` uses, so callers can place partial
+    /// completions directly without re-deriving the mapping. A group that
+    /// exhausts retries and every reroute degrades to one error `StreamChunk`
+    /// per trajectory in that group instead of ending the whole stream, so a
+    /// single down engine doesn't take out every other group's progress.
+    pub fn generate_stream(
+        &self,
+        input: InferenceEngineInput,
+    ) -> Result<impl Stream<Item = Result<StreamChunk, InferenceError>> + '_, InferenceError> {
+        if input.trajectory_ids.is_none() {
+            return Err(InferenceError::InvalidInput(
+                "trajectory_ids required for generate_stream".to_string(),
+            ));
+        }
+
+        let trajectory_items = self.create_trajectory_items(&input)?;
+        let engine_groups = self.group_trajectories_by_engine(trajectory_items);
+
+        let mut in_flight = FuturesUnordered::new();
+        for (engine_idx, traj_items) in engine_groups {
+            let engine_input =
+                self.build_engine_input_from_traj_items(&traj_items, &input.sampling_params)?;
+            in_flight.push(async move {
+                let result = self.call_with_retry_and_reroute(engine_idx, engine_input).await;
+                (traj_items, result)
+            });
+        }
+
+        let stream = futures::stream::unfold(in_flight, |mut in_flight| async move {
+            let (traj_items, result) = in_flight.next().await?;
+            let chunks: Vec<_> = match result {
+                Ok((output, used_fallback)) => traj_items
+                    .iter()
+                    .enumerate()
+                    .map(|(local_idx, item)| {
+                        Ok(StreamChunk {
+                            original_index: item.original_index,
+                            delta: output.responses[local_idx].clone(),
+                            stop_reason: Some(output.stop_reasons[local_idx].clone()),
+                            used_fallback: used_fallback || output.used_fallback[local_idx],
+                        })
+                    })
+                    .collect(),
+                Err(err) => {
+                    let stop_reason = Self::error_stop_reason(&err);
+                    traj_items
+                        .iter()
+                        .map(|item| {
+                            Ok(StreamChunk {
+                                original_index: item.original_index,
+                                delta: String::new(),
+                                stop_reason: Some(stop_reason.clone()),
+                                used_fallback: false,
+                            })
+                        })
+                        .collect()
+                }
+            };
+            Some((futures::stream::iter(chunks), in_flight))
+        })
+        .flatten();
+
+        Ok(stream)
+    }
+
     async fn generate_batched(
         &self,
         input: InferenceEngineInput,
@@ -111,6 +305,7 @@ impl InferenceEngineClient {
         let batch_size = items.len().div_ceil(num_engines);
 
         let mut tasks = Vec::new();
+        let mut batch_lens = Vec::new();
 
         for engine_idx in 0..num_engines {
             let start_idx = engine_idx * batch_size;
@@ -123,25 +318,235 @@ impl InferenceEngineClient {
             let batch_items = items[start_idx..end_idx].to_vec();
             let engine_input = create_engine_input(batch_items);
 
-            tasks.push(self.engines[engine_idx].generate(engine_input));
+            tasks.push(self.call_with_retry_and_reroute(engine_idx, engine_input));
+            batch_lens.push(end_idx - start_idx);
         }
 
-        // Execute all tasks and flatten results
+        // Execute all batches in parallel; a batch that exhausts its retries
+        // and every reroute falls back to per-slot error/timeout stop reasons
+        // instead of failing every other batch's responses too.
         let results = join_all(tasks).await;
         let mut responses = Vec::new();
         let mut stop_reasons = Vec::new();
-
-        for result in results {
-            let result = result?;
-            responses.extend(result.responses);
-            stop_reasons.extend(result.stop_reasons);
+        let mut used_fallback = Vec::new();
+
+        for (result, batch_len) in results.into_iter().zip(batch_lens) {
+            let output = match result {
+                Ok((mut output, did_fallback)) => {
+                    if did_fallback {
+                        output.used_fallback.iter_mut().for_each(|f| *f = true);
+                    }
+                    output
+                }
+                Err(err) => Self::fallback_output_for_error(&err, batch_len),
+            };
+            responses.extend(output.responses);
+            stop_reasons.extend(output.stop_reasons);
+            used_fallback.extend(output.used_fallback);
         }
 
         Ok(InferenceEngineOutput {
             responses,
             stop_reasons,
+            used_fallback,
         })
     }
+
+    /// Call a single engine with exponential backoff, and on repeated/terminal
+    /// failure transparently reroute to the next engine instead of failing
+    /// the whole request.
+    async fn call_with_retry_and_reroute(
+        &self,
+        engine_idx: usize,
+        engine_input: InferenceEngineInput,
+    ) -> Result<(InferenceEngineOutput, bool), InferenceError> {
+        let mut current_idx = engine_idx;
+        let mut used_fallback = false;
+        let mut tried = HashSet::new();
+
+        loop {
+            tried.insert(current_idx);
+
+            match self.call_with_backoff(current_idx, &engine_input).await {
+                Ok(output) => return Ok((output, used_fallback)),
+                Err(err) => {
+                    if tried.len() >= self.engines.len() {
+                        return Err(err);
+                    }
+                    self.metrics.record_retry(current_idx);
+                    current_idx = self.next_engine_in_ring(current_idx, &tried);
+                    used_fallback = true;
+                }
+            }
+        }
+    }
+
+    /// Call one engine, retrying with exponential backoff per `self.retry_policy`.
+    async fn call_with_backoff(
+        &self,
+        engine_idx: usize,
+        engine_input: &InferenceEngineInput,
+    ) -> Result<InferenceEngineOutput, InferenceError> {
+        let mut attempt = 0;
+        loop {
+            match self.call_with_timeout(engine_idx, engine_input).await {
+                Ok(output) => return Ok(output),
+                Err(err) if attempt + 1 < self.retry_policy.max_attempts => {
+                    self.metrics.record_retry(engine_idx);
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Retries `make_attempt` with exponential backoff per `self.retry_policy`,
+    /// same attempt/delay schedule as [`Self::call_with_backoff`] but for the
+    /// lifecycle fan-out calls (`wake_up`, `sleep`, `teardown`,
+    /// `reset_prefix_cache`) that have no per-request timeout or
+    /// retry-rerouting of their own — just a single engine that deserves a
+    /// few attempts before its failure is propagated.
+    async fn retry_with_backoff<F, Fut, T>(
+        &self,
+        engine_idx: usize,
+        mut make_attempt: F,
+    ) -> Result<T, InferenceError>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, InferenceError>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match make_attempt().await {
+                Ok(value) => return Ok(value),
+                Err(err) if attempt + 1 < self.retry_policy.max_attempts => {
+                    self.metrics.record_retry(engine_idx);
+                    tokio::time::sleep(self.retry_policy.delay_for_attempt(attempt)).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Calls one engine bounded by `self.retry_policy.per_attempt_timeout`,
+    /// turning an elapsed deadline into `InferenceError::Timeout` instead of
+    /// letting a hung engine block `join_all` forever. Records the
+    /// per-engine request count, in-flight gauge, latency, and (on success)
+    /// token throughput for this attempt.
+    async fn call_with_timeout(
+        &self,
+        engine_idx: usize,
+        engine_input: &InferenceEngineInput,
+    ) -> Result<InferenceEngineOutput, InferenceError> {
+        self.metrics.record_request(engine_idx);
+        self.metrics.in_flight_inc(engine_idx);
+        let started_at = Instant::now();
+
+        let timeout = self.retry_policy.per_attempt_timeout;
+        let result = match tokio::time::timeout(
+            timeout,
+            self.engines[engine_idx].generate(engine_input.clone()),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(InferenceError::Timeout {
+                seconds: timeout.as_secs(),
+            }),
+        };
+
+        self.metrics.in_flight_dec(engine_idx);
+        self.metrics.observe_latency(engine_idx, started_at.elapsed());
+        match &result {
+            Ok(output) => {
+                let tokens_in = engine_input
+                    .prompt_token_ids
+                    .as_ref()
+                    .map(|batches| batches.iter().map(|ids| ids.len() as u64).sum())
+                    .unwrap_or(0);
+                let tokens_out = output
+                    .responses
+                    .iter()
+                    .map(|r| r.split_whitespace().count() as u64)
+                    .sum();
+                self.metrics.record_tokens(engine_idx, tokens_in, tokens_out);
+            }
+            Err(err) => self.metrics.record_error(&Self::error_stop_reason(err)),
+        }
+
+        result
+    }
+
+    /// Maps an `InferenceError` to the `StopReason` it would surface as on
+    /// an engine's output slots, so per-attempt failures and per-slot
+    /// fallback failures land in the same `errors_total` buckets.
+    fn error_stop_reason(err: &InferenceError) -> StopReason {
+        match err {
+            InferenceError::Timeout { .. } => StopReason::Timeout,
+            _ => StopReason::Error,
+        }
+    }
+
+    /// Does the actual work of `update_named_weight`; split out so the
+    /// public trait method can wrap it uniformly with lifecycle metrics
+    /// regardless of which branch below returns.
+    async fn update_named_weight_inner(
+        &self,
+        request: &NamedWeightUpdateRequest,
+    ) -> Result<String, InferenceError> {
+        let last_version = self.weight_version.load(Ordering::SeqCst);
+        if request.weight_version <= last_version {
+            return Err(InferenceError::CommunicationError(format!(
+                "stale or out-of-order weight update for '{}': version {} <= last accepted version {}",
+                request.name, request.weight_version, last_version
+            )))
+            .context("weight_name", &request.name);
+        }
+
+        let tasks: Vec<_> = self
+            .engines
+            .iter()
+            .map(|engine| engine.update_named_weight(request.clone()))
+            .collect();
+
+        let results = join_all(tasks).await;
+
+        let mut checksums = Vec::with_capacity(results.len());
+        for (engine_idx, result) in results.into_iter().enumerate() {
+            let checksum = result
+                .context("weight_name", &request.name)
+                .context("engine_idx", engine_idx)?;
+            checksums.push((engine_idx, checksum));
+        }
+
+        if let Some(expected) = &request.checksum {
+            let mismatched: Vec<String> = checksums
+                .iter()
+                .filter(|(_, checksum)| checksum != expected)
+                .map(|(engine_idx, checksum)| format!("engine {engine_idx} got {checksum}"))
+                .collect();
+
+            if !mismatched.is_empty() {
+                return Err(InferenceError::CommunicationError(format!(
+                    "checksum mismatch for '{}' (expected {}): {}",
+                    request.name,
+                    expected,
+                    mismatched.join(", ")
+                )));
+            }
+        }
+
+        self.weight_version
+            .store(request.weight_version, Ordering::SeqCst);
+
+        Ok(checksums
+            .into_iter()
+            .next()
+            .map(|(_, checksum)| checksum)
+            .unwrap_or_default())
+    }
 }
 
 // Helper methods
@@ -228,11 +633,16 @@ impl InferenceEngineClient {
             let engine_input =
                 self.build_engine_input_from_traj_items(&traj_items, sampling_params)?;
 
-            tasks.push(self.engines[engine_idx].generate(engine_input));
+            tasks.push(self.call_with_retry_and_reroute(engine_idx, engine_input));
             engine_indices.push(engine_idx);
             traj_item_lists.push(traj_items);
         }
 
+        // Execute all groups in parallel; a flaky engine no longer fails the
+        // whole batch, since each group independently retries/reroutes, and a
+        // group that exhausts every engine falls back to per-slot
+        // error/timeout stop reasons instead of aborting every other group's
+        // results.
         let results = join_all(tasks).await;
 
         // Rebuild the semantic mapping
@@ -242,12 +652,33 @@ impl InferenceEngineClient {
             .zip(traj_item_lists.into_iter())
             .zip(results.into_iter())
         {
-            engine_results.insert(engine_idx, (traj_items, result?));
+            let output = match result {
+                Ok((mut output, did_fallback)) => {
+                    if did_fallback {
+                        output.used_fallback.iter_mut().for_each(|f| *f = true);
+                    }
+                    output
+                }
+                Err(err) => Self::fallback_output_for_error(&err, traj_items.len()),
+            };
+            engine_results.insert(engine_idx, (traj_items, output));
         }
 
         Ok(engine_results)
     }
 
+    /// Builds a per-slot fallback output for a trajectory/batch group that
+    /// exhausted every retry and reroute, so one stubborn group degrades its
+    /// own slots instead of failing the entire `generate` call.
+    fn fallback_output_for_error(err: &InferenceError, len: usize) -> InferenceEngineOutput {
+        let stop_reason = Self::error_stop_reason(err);
+        InferenceEngineOutput {
+            responses: vec![String::new(); len],
+            stop_reasons: vec![stop_reason; len],
+            used_fallback: vec![true; len],
+        }
+    }
+
     fn build_engine_input_from_traj_items(
         &self,
         traj_items: &[TrajectoryItem],
@@ -316,22 +747,27 @@ impl InferenceEngineClient {
         let mut responses = vec![String::new(); total_length];
         let mut stop_reasons =
             vec![crate::inference_old::types::StopReason::Other("unset".to_string()); total_length];
+        let mut used_fallback = vec![false; total_length];
 
         // Now we can iterate semantically over the engine results
         for (_, (traj_items, output)) in engine_results {
             // Place each result back in its original position
-            for (traj_item, (response, stop_reason)) in traj_items
-                .iter()
-                .zip(output.responses.iter().zip(output.stop_reasons.iter()))
-            {
+            for (traj_item, (response, (stop_reason, fallback))) in traj_items.iter().zip(
+                output
+                    .responses
+                    .iter()
+                    .zip(output.stop_reasons.iter().zip(output.used_fallback.iter())),
+            ) {
                 responses[traj_item.original_index] = response.clone();
                 stop_reasons[traj_item.original_index] = stop_reason.clone();
+                used_fallback[traj_item.original_index] = *fallback;
             }
         }
 
         Ok(InferenceEngineOutput {
             responses,
             stop_reasons,
+            used_fallback,
         })
     }
 }
@@ -366,35 +802,55 @@ impl InferenceEngine for InferenceEngineClient {
     }
 
     async fn wake_up(&self, tags: Option<Vec<String>>) -> Result<(), InferenceError> {
+        self.metrics.record_lifecycle_request("wake_up");
+        let started_at = Instant::now();
+
         let tasks: Vec<_> = self
             .engines
             .iter()
-            .map(|engine| engine.wake_up(tags.clone()))
+            .enumerate()
+            .map(|(engine_idx, engine)| {
+                self.retry_with_backoff(engine_idx, || engine.wake_up(tags.clone()))
+            })
             .collect();
 
         let results = join_all(tasks).await;
 
         // Check if any failed
         for result in results {
+            if result.is_err() {
+                self.metrics.record_lifecycle_error("wake_up");
+            }
             result?; // Propagate any errors
         }
 
+        self.metrics
+            .observe_lifecycle_latency("wake_up", started_at.elapsed());
         Ok(())
     }
 
     async fn sleep(&self, level: Option<i32>) -> Result<(), InferenceError> {
+        self.metrics.record_lifecycle_request("sleep");
+        let started_at = Instant::now();
+
         let tasks: Vec<_> = self
             .engines
             .iter()
-            .map(|engine| engine.sleep(level))
+            .enumerate()
+            .map(|(engine_idx, engine)| self.retry_with_backoff(engine_idx, || engine.sleep(level)))
             .collect();
 
         let results = join_all(tasks).await;
 
         for result in results {
+            if result.is_err() {
+                self.metrics.record_lifecycle_error("sleep");
+            }
             result?;
         }
 
+        self.metrics
+            .observe_lifecycle_latency("sleep", started_at.elapsed());
         Ok(())
     }
 
@@ -429,37 +885,41 @@ impl InferenceEngine for InferenceEngineClient {
 
         let results = join_all(tasks).await;
 
-        for result in results {
-            result?;
+        for (engine_idx, result) in results.into_iter().enumerate() {
+            result.context("engine_idx", engine_idx)?;
         }
 
+        // A freshly (re-)initialized communicator starts a new weight-update
+        // sequence, so the next `update_named_weight` call just needs a
+        // version greater than 0.
+        self.weight_version.store(0, Ordering::SeqCst);
+
         Ok(())
     }
 
     async fn update_named_weight(
         &self,
         request: NamedWeightUpdateRequest,
-    ) -> Result<(), InferenceError> {
-        let tasks: Vec<_> = self
-            .engines
-            .iter()
-            .map(|engine| engine.update_named_weight(request.clone()))
-            .collect();
+    ) -> Result<String, InferenceError> {
+        self.metrics.record_lifecycle_request("update_named_weight");
+        let started_at = Instant::now();
 
-        let results = join_all(tasks).await;
+        let result = self.update_named_weight_inner(&request).await;
 
-        for result in results {
-            result?;
+        self.metrics
+            .observe_lifecycle_latency("update_named_weight", started_at.elapsed());
+        if result.is_err() {
+            self.metrics.record_lifecycle_error("update_named_weight");
         }
-
-        Ok(())
+        result
     }
 
     async fn teardown(&self) -> Result<(), InferenceError> {
         let tasks: Vec<_> = self
             .engines
             .iter()
-            .map(|engine| engine.teardown())
+            .enumerate()
+            .map(|(engine_idx, engine)| self.retry_with_backoff(engine_idx, || engine.teardown()))
             .collect();
 
         let results = join_all(tasks).await;
@@ -475,7 +935,10 @@ impl InferenceEngine for InferenceEngineClient {
         let tasks: Vec<_> = self
             .engines
             .iter()
-            .map(|engine| engine.reset_prefix_cache())
+            .enumerate()
+            .map(|(engine_idx, engine)| {
+                self.retry_with_backoff(engine_idx, || engine.reset_prefix_cache())
+            })
             .collect();
 
         let results = join_all(tasks).await;
@@ -495,13 +958,12 @@ mod tests {
     #[tokio::test]
     async fn test_generate_with_trajectory_routing_three_prompts() {
         // Arrange
-        let client = InferenceEngineClient {
-            engines: vec![
-                Box::new(MockEngine::new("Engine0")),
-                Box::new(MockEngine::new("Engine1")),
-                Box::new(MockEngine::new("Engine2")),
-            ],
-        };
+        let client = InferenceEngineClient::new(vec![
+            Box::new(MockEngine::new("Engine0")),
+            Box::new(MockEngine::new("Engine1")),
+            Box::new(MockEngine::new("Engine2")),
+        ])
+        .unwrap();
 
         // Create input with 3 prompts and trajectory IDs
         let input = InferenceEngineInput {
@@ -563,12 +1025,18 @@ mod tests {
 
     struct MockEngine {
         name: String,
+        tp_size: usize,
     }
 
     impl MockEngine {
         fn new(name: &str) -> Self {
+            Self::with_tp_size(name, 1)
+        }
+
+        fn with_tp_size(name: &str, tp_size: usize) -> Self {
             MockEngine {
                 name: name.to_string(),
+                tp_size,
             }
         }
     }
@@ -576,7 +1044,7 @@ mod tests {
     #[async_trait]
     impl InferenceEngine for MockEngine {
         fn tp_size(&self) -> usize {
-            1
+            self.tp_size
         }
 
         async fn generate(
@@ -599,10 +1067,12 @@ mod tests {
                 .collect();
 
             let stop_reasons = vec![crate::inference_old::types::StopReason::Stop; num_prompts];
+            let used_fallback = vec![false; num_prompts];
 
             Ok(InferenceEngineOutput {
                 responses,
                 stop_reasons,
+                used_fallback,
             })
         }
 
@@ -630,10 +1100,65 @@ mod tests {
         async fn update_named_weight(
             &self,
             _request: NamedWeightUpdateRequest,
+        ) -> Result<String, InferenceError> {
+            Ok(String::new())
+        }
+
+        async fn teardown(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn reset_prefix_cache(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+    }
+
+    /// Always fails `generate`, so tests can exercise the retry/reroute path.
+    struct AlwaysFailEngine;
+
+    #[async_trait]
+    impl InferenceEngine for AlwaysFailEngine {
+        fn tp_size(&self) -> usize {
+            1
+        }
+
+        async fn generate(
+            &self,
+            _input: InferenceEngineInput,
+        ) -> Result<InferenceEngineOutput, InferenceError> {
+            Err(InferenceError::CommunicationError(
+                "engine unreachable".to_string(),
+            ))
+        }
+
+        async fn wake_up(&self, _tags: Option<Vec<String>>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn sleep(&self, _level: Option<i32>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn init_weight_update_communicator(
+            &self,
+            _master_addr: String,
+            _master_port: u16,
+            _rank_offset: usize,
+            _world_size: usize,
+            _group_name: String,
+            _backend: String,
+            _override_existing: bool,
         ) -> Result<(), InferenceError> {
             Ok(())
         }
 
+        async fn update_named_weight(
+            &self,
+            _request: NamedWeightUpdateRequest,
+        ) -> Result<String, InferenceError> {
+            Ok(String::new())
+        }
+
         async fn teardown(&self) -> Result<(), InferenceError> {
             Ok(())
         }
@@ -642,4 +1167,608 @@ mod tests {
             Ok(())
         }
     }
+
+    fn fast_retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 2,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+            jitter: 0.0,
+            per_attempt_timeout: std::time::Duration::from_secs(30),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_with_retry_and_reroute_falls_back_to_healthy_engine() {
+        let client = InferenceEngineClient::new(vec![
+            Box::new(AlwaysFailEngine),
+            Box::new(MockEngine::new("Engine1")),
+        ])
+        .unwrap()
+        .with_retry_policy(fast_retry_policy());
+
+        let input = InferenceEngineInput {
+            prompts: Some(vec![vec![Message {
+                role: "user".to_string(),
+                content: "hi".to_string(),
+            }]]),
+            prompt_token_ids: None,
+            sampling_params: None,
+            trajectory_ids: None,
+        };
+
+        let (output, used_fallback) = client
+            .call_with_retry_and_reroute(0, input)
+            .await
+            .expect("should reroute to the healthy engine");
+
+        assert!(used_fallback, "should have rerouted off engine 0");
+        assert_eq!(output.responses.len(), 1);
+        assert!(output.responses[0].contains("Engine1"));
+    }
+
+    #[tokio::test]
+    async fn test_call_with_retry_and_reroute_fails_when_all_engines_down() {
+        let client = InferenceEngineClient::new(vec![
+            Box::new(AlwaysFailEngine),
+            Box::new(AlwaysFailEngine),
+        ])
+        .unwrap()
+        .with_retry_policy(fast_retry_policy());
+
+        let input = InferenceEngineInput {
+            prompts: Some(vec![vec![Message {
+                role: "user".to_string(),
+                content: "hi".to_string(),
+            }]]),
+            prompt_token_ids: None,
+            sampling_params: None,
+            trajectory_ids: None,
+        };
+
+        let result = client.call_with_retry_and_reroute(0, input).await;
+        assert!(result.is_err(), "should fail once every engine is exhausted");
+    }
+
+    /// An engine whose `generate` outlasts any reasonable `per_attempt_timeout`.
+    struct SlowEngine;
+
+    #[async_trait]
+    impl InferenceEngine for SlowEngine {
+        fn tp_size(&self) -> usize {
+            1
+        }
+
+        async fn generate(
+            &self,
+            _input: InferenceEngineInput,
+        ) -> Result<InferenceEngineOutput, InferenceError> {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            Ok(InferenceEngineOutput {
+                responses: vec!["too late".to_string()],
+                stop_reasons: vec![crate::inference_old::types::StopReason::Stop],
+                used_fallback: vec![false],
+            })
+        }
+
+        async fn wake_up(&self, _tags: Option<Vec<String>>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn sleep(&self, _level: Option<i32>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn init_weight_update_communicator(
+            &self,
+            _master_addr: String,
+            _master_port: u16,
+            _rank_offset: usize,
+            _world_size: usize,
+            _group_name: String,
+            _backend: String,
+            _override_existing: bool,
+        ) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn update_named_weight(
+            &self,
+            _request: NamedWeightUpdateRequest,
+        ) -> Result<String, InferenceError> {
+            Ok(String::new())
+        }
+
+        async fn teardown(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn reset_prefix_cache(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_with_backoff_times_out_slow_engine() {
+        let client = InferenceEngineClient::new(vec![Box::new(SlowEngine)])
+            .unwrap()
+            .with_retry_policy(RetryPolicy {
+                max_attempts: 1,
+                base_delay: std::time::Duration::from_millis(1),
+                max_delay: std::time::Duration::from_millis(1),
+                jitter: 0.0,
+                per_attempt_timeout: std::time::Duration::from_millis(20),
+            });
+
+        let input = InferenceEngineInput {
+            prompts: Some(vec![vec![Message {
+                role: "user".to_string(),
+                content: "hi".to_string(),
+            }]]),
+            prompt_token_ids: None,
+            sampling_params: None,
+            trajectory_ids: None,
+        };
+
+        let result = client.call_with_backoff(0, &input).await;
+        assert!(matches!(result, Err(InferenceError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_generate_batched_degrades_to_fallback_when_engine_is_down() {
+        let client = InferenceEngineClient::new(vec![Box::new(AlwaysFailEngine)])
+            .unwrap()
+            .with_retry_policy(fast_retry_policy());
+
+        let input = InferenceEngineInput {
+            prompts: Some(vec![
+                vec![Message {
+                    role: "user".to_string(),
+                    content: "a".to_string(),
+                }],
+                vec![Message {
+                    role: "user".to_string(),
+                    content: "b".to_string(),
+                }],
+            ]),
+            prompt_token_ids: None,
+            sampling_params: None,
+            trajectory_ids: None,
+        };
+
+        // Only engine is down and there's nowhere to reroute to, but the
+        // whole batch still degrades to per-slot fallback output instead of
+        // failing the call.
+        let output = client
+            .generate(input)
+            .await
+            .expect("should degrade instead of failing");
+
+        assert_eq!(output.responses.len(), 2);
+        assert!(output.used_fallback.iter().all(|&f| f));
+        assert!(output
+            .stop_reasons
+            .iter()
+            .all(|r| matches!(r, crate::inference_old::types::StopReason::Error)));
+    }
+
+    #[test]
+    fn test_calculate_engine_index_is_stable_for_same_trajectory_id() {
+        let client = InferenceEngineClient::new(vec![
+            Box::new(MockEngine::new("Engine0")),
+            Box::new(MockEngine::new("Engine1")),
+            Box::new(MockEngine::new("Engine2")),
+        ])
+        .unwrap();
+
+        let first = client.calculate_engine_index("traj_001");
+        for _ in 0..10 {
+            assert_eq!(client.calculate_engine_index("traj_001"), first);
+        }
+    }
+
+    #[test]
+    fn test_calculate_engine_index_only_remaps_a_fraction_on_engine_added() {
+        let before = InferenceEngineClient::new(vec![
+            Box::new(MockEngine::new("Engine0")),
+            Box::new(MockEngine::new("Engine1")),
+            Box::new(MockEngine::new("Engine2")),
+        ])
+        .unwrap();
+
+        let after = InferenceEngineClient::new(vec![
+            Box::new(MockEngine::new("Engine0")),
+            Box::new(MockEngine::new("Engine1")),
+            Box::new(MockEngine::new("Engine2")),
+            Box::new(MockEngine::new("Engine3")),
+        ])
+        .unwrap();
+
+        let trajectory_ids: Vec<String> = (0..500).map(|i| format!("traj_{i}")).collect();
+        let remapped = trajectory_ids
+            .iter()
+            .filter(|id| before.calculate_engine_index(id) != after.calculate_engine_index(id))
+            .count();
+
+        // Consistent hashing should remap roughly 1/N of keys when adding the
+        // Nth engine (here ~1/4), not the ~3/4 a plain-modulo scheme would.
+        assert!(
+            remapped < trajectory_ids.len() / 2,
+            "expected a bounded remap, got {remapped}/{}",
+            trajectory_ids.len()
+        );
+    }
+
+    #[test]
+    fn test_ring_weights_traffic_by_tp_size() {
+        // Engine 1 has 3x the tp_size of engine 0, so it should get roughly
+        // 3x engine 0's share of routed trajectories.
+        let client = InferenceEngineClient::new(vec![
+            Box::new(MockEngine::with_tp_size("E0", 1)),
+            Box::new(MockEngine::with_tp_size("E1", 3)),
+        ])
+        .unwrap();
+
+        let mut counts = [0usize; 2];
+        for i in 0..4000 {
+            counts[client.calculate_engine_index(&format!("traj-{i}"))] += 1;
+        }
+
+        let ratio = counts[1] as f64 / counts[0] as f64;
+        assert!(
+            (2.0..4.0).contains(&ratio),
+            "expected engine 1 to get ~3x engine 0's traffic, got ratio {ratio} ({counts:?})"
+        );
+    }
+
+    #[test]
+    fn test_next_engine_in_ring_skips_tried_engines() {
+        let client = InferenceEngineClient::new(vec![
+            Box::new(MockEngine::new("Engine0")),
+            Box::new(MockEngine::new("Engine1")),
+            Box::new(MockEngine::new("Engine2")),
+        ])
+        .unwrap();
+
+        let mut tried = HashSet::new();
+        tried.insert(0);
+        tried.insert(1);
+
+        let next = client.next_engine_in_ring(0, &tried);
+        assert_eq!(next, 2, "should skip already-tried engines 0 and 1");
+    }
+
+    #[tokio::test]
+    async fn test_generate_stream_yields_a_chunk_per_trajectory() {
+        let client = InferenceEngineClient::new(vec![
+            Box::new(MockEngine::new("Engine0")),
+            Box::new(MockEngine::new("Engine1")),
+        ])
+        .unwrap();
+
+        let input = InferenceEngineInput {
+            prompts: Some(vec![
+                vec![Message {
+                    role: "user".to_string(),
+                    content: "Prompt 1".to_string(),
+                }],
+                vec![Message {
+                    role: "user".to_string(),
+                    content: "Prompt 2".to_string(),
+                }],
+            ]),
+            prompt_token_ids: None,
+            sampling_params: None,
+            trajectory_ids: Some(vec!["traj_001".to_string(), "traj_002".to_string()]),
+        };
+
+        let stream = client.generate_stream(input).expect("valid input");
+        let mut chunks: Vec<StreamChunk> = stream.map(|c| c.expect("chunk")).collect().await;
+        chunks.sort_by_key(|c| c.original_index);
+
+        assert_eq!(chunks.len(), 2, "should yield one chunk per trajectory");
+        assert_eq!(chunks[0].original_index, 0);
+        assert_eq!(chunks[1].original_index, 1);
+        assert!(!chunks[0].used_fallback);
+    }
+
+    #[tokio::test]
+    async fn test_generate_stream_degrades_a_failed_group_to_per_slot_error_chunks() {
+        let client = InferenceEngineClient::new(vec![Box::new(AlwaysFailEngine)])
+            .unwrap()
+            .with_retry_policy(fast_retry_policy());
+
+        let input = InferenceEngineInput {
+            prompts: Some(vec![vec![Message {
+                role: "user".to_string(),
+                content: "hi".to_string(),
+            }]]),
+            prompt_token_ids: None,
+            sampling_params: None,
+            trajectory_ids: Some(vec!["traj_001".to_string()]),
+        };
+
+        let stream = client.generate_stream(input).expect("valid input");
+        let chunks: Vec<StreamChunk> = stream
+            .map(|c| c.expect("degraded to an Ok chunk, not a stream error"))
+            .collect()
+            .await;
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].original_index, 0);
+        assert!(matches!(chunks[0].stop_reason, Some(StopReason::Error)));
+    }
+
+    #[test]
+    fn test_generate_stream_requires_trajectory_ids() {
+        let client = InferenceEngineClient::new(vec![Box::new(MockEngine::new("Engine0"))]).unwrap();
+
+        let input = InferenceEngineInput {
+            prompts: Some(vec![vec![Message {
+                role: "user".to_string(),
+                content: "hi".to_string(),
+            }]]),
+            prompt_token_ids: None,
+            sampling_params: None,
+            trajectory_ids: None,
+        };
+
+        assert!(client.generate_stream(input).is_err());
+    }
+
+    /// Engine whose `update_named_weight` reports a fixed checksum, so a test
+    /// can simulate a tp-sharded replica that wrote a different tensor than
+    /// its peers.
+    struct ChecksumEngine {
+        checksum: String,
+    }
+
+    impl ChecksumEngine {
+        fn new(checksum: &str) -> Self {
+            Self {
+                checksum: checksum.to_string(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl InferenceEngine for ChecksumEngine {
+        fn tp_size(&self) -> usize {
+            1
+        }
+
+        async fn generate(
+            &self,
+            _input: InferenceEngineInput,
+        ) -> Result<InferenceEngineOutput, InferenceError> {
+            Ok(InferenceEngineOutput {
+                responses: vec![],
+                stop_reasons: vec![],
+                used_fallback: vec![],
+            })
+        }
+
+        async fn wake_up(&self, _tags: Option<Vec<String>>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn sleep(&self, _level: Option<i32>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn init_weight_update_communicator(
+            &self,
+            _master_addr: String,
+            _master_port: u16,
+            _rank_offset: usize,
+            _world_size: usize,
+            _group_name: String,
+            _backend: String,
+            _override_existing: bool,
+        ) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn update_named_weight(
+            &self,
+            _request: NamedWeightUpdateRequest,
+        ) -> Result<String, InferenceError> {
+            Ok(self.checksum.clone())
+        }
+
+        async fn teardown(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn reset_prefix_cache(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+    }
+
+    fn weight_update_request(weight_version: u64, checksum: Option<&str>) -> NamedWeightUpdateRequest {
+        NamedWeightUpdateRequest {
+            name: "policy.layer0".to_string(),
+            dtype: "bf16".to_string(),
+            shape: vec![4, 4],
+            extras: None,
+            checksum: checksum.map(|s| s.to_string()),
+            weight_version,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_named_weight_rejects_checksum_mismatch() {
+        let client = InferenceEngineClient::new(vec![
+            Box::new(ChecksumEngine::new("aaa")),
+            Box::new(ChecksumEngine::new("bbb")),
+        ])
+        .unwrap();
+
+        let result = client
+            .update_named_weight(weight_update_request(1, Some("aaa")))
+            .await;
+
+        match result {
+            Err(InferenceError::CommunicationError(msg)) => {
+                assert!(msg.contains("checksum mismatch"), "{msg}");
+                assert!(msg.contains("engine 1"), "{msg}");
+            }
+            other => panic!("expected a checksum mismatch error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_named_weight_rejects_stale_or_equal_version() {
+        let client = InferenceEngineClient::new(vec![Box::new(ChecksumEngine::new("same"))]).unwrap();
+
+        client
+            .update_named_weight(weight_update_request(1, None))
+            .await
+            .expect("first update establishes version 1");
+
+        // Same version again: rejected, not just "applied twice".
+        assert!(matches!(
+            client.update_named_weight(weight_update_request(1, None)).await,
+            Err(InferenceError::CommunicationError(_))
+        ));
+
+        // Lower version: also rejected.
+        assert!(matches!(
+            client.update_named_weight(weight_update_request(0, None)).await,
+            Err(InferenceError::CommunicationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_init_weight_update_communicator_resets_version_sequence() {
+        // `weight_version` is scoped to a single communicator generation, not
+        // to the client's whole lifetime: re-initializing starts a new
+        // distributed process group, so there's no ordering guarantee left
+        // to preserve across the reset, and the next sequence is free to
+        // start low again. This is intentional, not a monotonicity bug.
+        let client = InferenceEngineClient::new(vec![Box::new(ChecksumEngine::new("same"))]).unwrap();
+
+        client
+            .update_named_weight(weight_update_request(5, None))
+            .await
+            .expect("update accepted");
+
+        assert!(matches!(
+            client.update_named_weight(weight_update_request(2, None)).await,
+            Err(InferenceError::CommunicationError(_))
+        ));
+
+        client
+            .init_weight_update_communicator(
+                "localhost".to_string(),
+                29500,
+                0,
+                1,
+                "group".to_string(),
+                "nccl".to_string(),
+                true,
+            )
+            .await
+            .expect("communicator re-init");
+
+        client
+            .update_named_weight(weight_update_request(2, None))
+            .await
+            .expect("accepted again after re-init resets the sequence");
+    }
+
+    /// An engine whose `wake_up` fails its first `fail_times` calls, then
+    /// succeeds — stands in for a transient error `retry_with_backoff`
+    /// should absorb instead of propagating on the first attempt.
+    struct FlakyWakeEngine {
+        fail_times: usize,
+        attempts: std::sync::atomic::AtomicUsize,
+    }
+
+    impl FlakyWakeEngine {
+        fn new(fail_times: usize) -> Self {
+            Self {
+                fail_times,
+                attempts: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl InferenceEngine for FlakyWakeEngine {
+        fn tp_size(&self) -> usize {
+            1
+        }
+
+        async fn generate(
+            &self,
+            _input: InferenceEngineInput,
+        ) -> Result<InferenceEngineOutput, InferenceError> {
+            unimplemented!("not exercised by the wake_up retry test")
+        }
+
+        async fn wake_up(&self, _tags: Option<Vec<String>>) -> Result<(), InferenceError> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            if attempt < self.fail_times {
+                return Err(InferenceError::CommunicationError("transient".to_string()));
+            }
+            Ok(())
+        }
+
+        async fn sleep(&self, _level: Option<i32>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn init_weight_update_communicator(
+            &self,
+            _master_addr: String,
+            _master_port: u16,
+            _rank_offset: usize,
+            _world_size: usize,
+            _group_name: String,
+            _backend: String,
+            _override_existing: bool,
+        ) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn update_named_weight(
+            &self,
+            _request: NamedWeightUpdateRequest,
+        ) -> Result<String, InferenceError> {
+            Ok(String::new())
+        }
+
+        async fn teardown(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn reset_prefix_cache(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wake_up_retries_a_transient_failure_before_giving_up() {
+        let client = InferenceEngineClient::new(vec![Box::new(FlakyWakeEngine::new(1))])
+            .unwrap()
+            .with_retry_policy(fast_retry_policy());
+
+        client
+            .wake_up(None)
+            .await
+            .expect("should succeed once the transient failure is retried");
+    }
+
+    #[tokio::test]
+    async fn test_wake_up_fails_once_retries_are_exhausted() {
+        // `fast_retry_policy` allows 2 attempts; an engine that never
+        // succeeds within those should still surface as an error.
+        let client = InferenceEngineClient::new(vec![Box::new(FlakyWakeEngine::new(2))])
+            .unwrap()
+            .with_retry_policy(fast_retry_policy());
+
+        let result = client.wake_up(None).await;
+        assert!(result.is_err(), "should fail once every retry is exhausted");
+    }
 }
@@ -1,16 +1,14 @@
-// src/inference/engine.rs
-
+use super::types::*;
 use async_trait::async_trait;
-use crate::inference::types::*;
 
 #[async_trait]
 pub trait InferenceEngine: Send + Sync {
     async fn generate(&self, input: InferenceEngineInput) -> Result<InferenceEngineOutput, InferenceError>;
-    
-    async fn wake_up(&self) -> Result<(), InferenceError>;
-    
-    async fn sleep(&self) -> Result<(), InferenceError>;
-    
+
+    async fn wake_up(&self, tags: Option<Vec<String>>) -> Result<(), InferenceError>;
+
+    async fn sleep(&self, level: Option<i32>) -> Result<(), InferenceError>;
+
     async fn init_weight_update_communicator(
         &self,
         master_addr: String,
@@ -21,10 +19,14 @@ pub trait InferenceEngine: Send + Sync {
         backend: String,
         override_existing: bool,
     ) -> Result<(), InferenceError>;
-    
-    async fn update_named_weight(&self, request: NamedWeightUpdateRequest) -> Result<(), InferenceError>;
-    
+
+    // Returns the engine's sha256 digest of the tensor it wrote, so the
+    // caller can confirm every tp-sharded replica landed identical bytes.
+    async fn update_named_weight(&self, request: NamedWeightUpdateRequest) -> Result<String, InferenceError>;
+
     async fn teardown(&self) -> Result<(), InferenceError>;
-    
+
     async fn reset_prefix_cache(&self) -> Result<(), InferenceError>;
+
+    fn tp_size(&self) -> usize;
 }
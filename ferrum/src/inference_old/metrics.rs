@@ -0,0 +1,266 @@
+//! Observability for `InferenceEngineClient`: per-engine request/in-flight
+//! counts, generation latency, token throughput, retries, and
+//! timeout/error counts keyed by `StopReason`, plus pool lifecycle calls
+//! (`wake_up`, `sleep`, `update_named_weight`).
+//!
+//! Lives behind the `metrics` feature so the `prometheus` dependency is
+//! opt-in. With the feature off, `ClientMetrics` is a no-op with the exact
+//! same method signatures, so `client.rs`'s instrumentation call sites never
+//! need their own `#[cfg(feature = "metrics")]`.
+
+#[cfg(feature = "metrics")]
+use super::types::StopReason;
+#[cfg(feature = "metrics")]
+fn stop_reason_label(reason: &StopReason) -> &'static str {
+    match reason {
+        StopReason::Stop => "stop",
+        StopReason::Length => "length",
+        StopReason::Error => "error",
+        StopReason::Timeout => "timeout",
+        StopReason::Other(_) => "other",
+    }
+}
+
+#[cfg(feature = "metrics")]
+mod enabled {
+    use super::stop_reason_label;
+    use super::StopReason;
+    use prometheus::{Encoder, HistogramVec, IntCounterVec, IntGaugeVec, Registry, TextEncoder};
+    use std::time::Duration;
+
+    /// Live Prometheus collectors for one `InferenceEngineClient`, registered
+    /// against their own `Registry` so multiple clients in one process don't
+    /// collide on metric names.
+    pub struct ClientMetrics {
+        registry: Registry,
+        requests_total: IntCounterVec,
+        in_flight: IntGaugeVec,
+        generate_latency_seconds: HistogramVec,
+        tokens_in_total: IntCounterVec,
+        tokens_out_total: IntCounterVec,
+        retries_total: IntCounterVec,
+        errors_total: IntCounterVec,
+        lifecycle_requests_total: IntCounterVec,
+        lifecycle_latency_seconds: HistogramVec,
+        lifecycle_errors_total: IntCounterVec,
+    }
+
+    impl ClientMetrics {
+        pub fn new() -> Self {
+            let registry = Registry::new();
+
+            let requests_total = IntCounterVec::new(
+                prometheus::opts!("ferrum_inference_requests_total", "Engine calls made by the client"),
+                &["engine_idx"],
+            )
+            .unwrap();
+            let in_flight = IntGaugeVec::new(
+                prometheus::opts!(
+                    "ferrum_inference_in_flight_requests",
+                    "Engine calls currently awaiting a response"
+                ),
+                &["engine_idx"],
+            )
+            .unwrap();
+            let generate_latency_seconds = HistogramVec::new(
+                prometheus::histogram_opts!(
+                    "ferrum_inference_generate_latency_seconds",
+                    "Wall-clock time of a single engine generate call"
+                ),
+                &["engine_idx"],
+            )
+            .unwrap();
+            let tokens_in_total = IntCounterVec::new(
+                prometheus::opts!("ferrum_inference_tokens_in_total", "Prompt tokens sent to engines"),
+                &["engine_idx"],
+            )
+            .unwrap();
+            let tokens_out_total = IntCounterVec::new(
+                prometheus::opts!(
+                    "ferrum_inference_tokens_out_total",
+                    "Completion tokens received from engines"
+                ),
+                &["engine_idx"],
+            )
+            .unwrap();
+            let retries_total = IntCounterVec::new(
+                prometheus::opts!("ferrum_inference_retries_total", "Retry/reroute attempts issued"),
+                &["engine_idx"],
+            )
+            .unwrap();
+            let errors_total = IntCounterVec::new(
+                prometheus::opts!(
+                    "ferrum_inference_errors_total",
+                    "Terminal generate failures, keyed by stop reason"
+                ),
+                &["stop_reason"],
+            )
+            .unwrap();
+            let lifecycle_requests_total = IntCounterVec::new(
+                prometheus::opts!(
+                    "ferrum_inference_lifecycle_requests_total",
+                    "Pool lifecycle calls made by the client (wake_up, sleep, weight updates)"
+                ),
+                &["op"],
+            )
+            .unwrap();
+            let lifecycle_latency_seconds = HistogramVec::new(
+                prometheus::histogram_opts!(
+                    "ferrum_inference_lifecycle_latency_seconds",
+                    "Wall-clock time of a pool lifecycle call, fanned out across every engine"
+                ),
+                &["op"],
+            )
+            .unwrap();
+            let lifecycle_errors_total = IntCounterVec::new(
+                prometheus::opts!(
+                    "ferrum_inference_lifecycle_errors_total",
+                    "Failed pool lifecycle calls"
+                ),
+                &["op"],
+            )
+            .unwrap();
+
+            registry.register(Box::new(requests_total.clone())).unwrap();
+            registry.register(Box::new(in_flight.clone())).unwrap();
+            registry
+                .register(Box::new(generate_latency_seconds.clone()))
+                .unwrap();
+            registry.register(Box::new(tokens_in_total.clone())).unwrap();
+            registry.register(Box::new(tokens_out_total.clone())).unwrap();
+            registry.register(Box::new(retries_total.clone())).unwrap();
+            registry.register(Box::new(errors_total.clone())).unwrap();
+            registry
+                .register(Box::new(lifecycle_requests_total.clone()))
+                .unwrap();
+            registry
+                .register(Box::new(lifecycle_latency_seconds.clone()))
+                .unwrap();
+            registry
+                .register(Box::new(lifecycle_errors_total.clone()))
+                .unwrap();
+
+            Self {
+                registry,
+                requests_total,
+                in_flight,
+                generate_latency_seconds,
+                tokens_in_total,
+                tokens_out_total,
+                retries_total,
+                errors_total,
+                lifecycle_requests_total,
+                lifecycle_latency_seconds,
+                lifecycle_errors_total,
+            }
+        }
+
+        pub fn record_request(&self, engine_idx: usize) {
+            self.requests_total
+                .with_label_values(&[&engine_idx.to_string()])
+                .inc();
+        }
+
+        pub fn in_flight_inc(&self, engine_idx: usize) {
+            self.in_flight.with_label_values(&[&engine_idx.to_string()]).inc();
+        }
+
+        pub fn in_flight_dec(&self, engine_idx: usize) {
+            self.in_flight.with_label_values(&[&engine_idx.to_string()]).dec();
+        }
+
+        pub fn observe_latency(&self, engine_idx: usize, elapsed: Duration) {
+            self.generate_latency_seconds
+                .with_label_values(&[&engine_idx.to_string()])
+                .observe(elapsed.as_secs_f64());
+        }
+
+        pub fn record_tokens(&self, engine_idx: usize, tokens_in: u64, tokens_out: u64) {
+            let label = engine_idx.to_string();
+            self.tokens_in_total.with_label_values(&[&label]).inc_by(tokens_in);
+            self.tokens_out_total.with_label_values(&[&label]).inc_by(tokens_out);
+        }
+
+        pub fn record_retry(&self, engine_idx: usize) {
+            self.retries_total
+                .with_label_values(&[&engine_idx.to_string()])
+                .inc();
+        }
+
+        pub fn record_error(&self, stop_reason: &StopReason) {
+            self.errors_total
+                .with_label_values(&[stop_reason_label(stop_reason)])
+                .inc();
+        }
+
+        /// `op` is a short fixed label such as `"wake_up"`, `"sleep"`, or
+        /// `"update_named_weight"` — one of a small known set, so it's safe
+        /// as a Prometheus label without risking cardinality blowup.
+        pub fn record_lifecycle_request(&self, op: &str) {
+            self.lifecycle_requests_total.with_label_values(&[op]).inc();
+        }
+
+        pub fn observe_lifecycle_latency(&self, op: &str, elapsed: Duration) {
+            self.lifecycle_latency_seconds
+                .with_label_values(&[op])
+                .observe(elapsed.as_secs_f64());
+        }
+
+        pub fn record_lifecycle_error(&self, op: &str) {
+            self.lifecycle_errors_total.with_label_values(&[op]).inc();
+        }
+
+        /// Render every registered collector in Prometheus text exposition
+        /// format, for `InferenceEngineClient::metrics_handle` to serve.
+        pub fn scrape(&self) -> String {
+            let metric_families = self.registry.gather();
+            let mut buf = Vec::new();
+            TextEncoder::new().encode(&metric_families, &mut buf).unwrap();
+            String::from_utf8(buf).unwrap()
+        }
+    }
+
+    impl Default for ClientMetrics {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+mod disabled {
+    use crate::inference_old::types::StopReason;
+    use std::time::Duration;
+
+    /// No-op stand-in for the Prometheus-backed `ClientMetrics` used when
+    /// the `metrics` feature is off, so `InferenceEngineClient`'s
+    /// instrumentation call sites compile (and cost nothing) either way.
+    #[derive(Default)]
+    pub struct ClientMetrics;
+
+    impl ClientMetrics {
+        pub fn new() -> Self {
+            Self
+        }
+
+        pub fn record_request(&self, _engine_idx: usize) {}
+        pub fn in_flight_inc(&self, _engine_idx: usize) {}
+        pub fn in_flight_dec(&self, _engine_idx: usize) {}
+        pub fn observe_latency(&self, _engine_idx: usize, _elapsed: Duration) {}
+        pub fn record_tokens(&self, _engine_idx: usize, _tokens_in: u64, _tokens_out: u64) {}
+        pub fn record_retry(&self, _engine_idx: usize) {}
+        pub fn record_error(&self, _stop_reason: &StopReason) {}
+        pub fn record_lifecycle_request(&self, _op: &str) {}
+        pub fn observe_lifecycle_latency(&self, _op: &str, _elapsed: Duration) {}
+        pub fn record_lifecycle_error(&self, _op: &str) {}
+
+        pub fn scrape(&self) -> String {
+            String::new()
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+pub use enabled::ClientMetrics;
+#[cfg(not(feature = "metrics"))]
+pub use disabled::ClientMetrics;
@@ -0,0 +1,13 @@
+pub mod client;
+pub mod engine;
+pub mod metrics;
+pub mod types;
+
+// Create a clean public interface
+pub use types::{
+    ContextFrame, ContextualError, InferenceEngineInput, InferenceEngineOutput, InferenceError,
+    InferenceErrorContext, NamedWeightUpdateRequest, RetryPolicy, StopReason,
+};
+
+pub use client::InferenceEngineClient;
+pub use engine::InferenceEngine;
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+// Equivalent to Python's MessageType = Dict[str, str]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub role: String,    // "user", "assistant", "system"
+    pub content: String,
+}
+
+// Equivalent to Python's ConversationType = List[MessageType]
+pub type Conversation = Vec<Message>;
+
+// This is more type-safe than Python's Dict[str, Any]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingParams {
+    pub temperature: Option<f32>,
+    pub max_tokens: Option<u32>,
+    pub top_p: Option<f32>,
+    pub top_k: Option<u32>,
+    pub stop: Option<Vec<String>>,
+    // We can add more fields as needed, or use a HashMap for flexibility
+    pub extra: Option<HashMap<String, serde_json::Value>>,
+}
+
+// Rust equivalent of InferenceEngineInput
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InferenceEngineInput {
+    // Using Option<Vec<T>> like Python's Optional[List[T]]
+    pub prompts: Option<Vec<Conversation>>,
+    pub prompt_token_ids: Option<Vec<Vec<i32>>>,
+    pub sampling_params: Option<SamplingParams>,
+    // Using String for trajectory IDs (could be uuid::Uuid if we want type safety)
+    pub trajectory_ids: Option<Vec<String>>,
+}
+
+// Better than Python strings - we can enumerate all possible stop reasons
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum StopReason {
+    Stop,           // Normal completion
+    Length,         // Hit max length
+    Error,          // Some error occurred
+    Timeout,        // Request timed out
+    Other(String),  // Fallback for unknown reasons
+}
+
+// Rust equivalent of InferenceEngineOutput
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InferenceEngineOutput {
+    pub responses: Vec<String>,
+    pub stop_reasons: Vec<StopReason>,  // Much better than Vec<String>!
+    // Parallel to `responses`/`stop_reasons`: true wherever the slot was served
+    // by a fallback engine after the originally-routed engine failed.
+    pub used_fallback: Vec<bool>,
+}
+
+// Retry/backoff policy applied to individual engine calls made on behalf of
+// `generate`. Weight-update calls (`update_named_weight`,
+// `init_weight_update_communicator`) intentionally do not go through this
+// policy: a half-applied weight update across tp-sharded replicas is worse
+// than a hard failure, so those stay all-or-nothing.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: usize,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    // Fraction of the computed delay added as random jitter, e.g. 0.1 == +-10%.
+    pub jitter: f64,
+    // How long a single engine call is allowed to run before it's treated as
+    // failed with `InferenceError::Timeout`, so a hung engine can't stall
+    // `generate` forever.
+    pub per_attempt_timeout: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            jitter: 0.1,
+            per_attempt_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff delay before the given (zero-indexed) retry attempt,
+    /// capped at `max_delay` and perturbed by `jitter`.
+    pub(crate) fn delay_for_attempt(&self, attempt: usize) -> Duration {
+        let base_ms = self.base_delay.as_millis() as u64;
+        let shift = attempt.min(16) as u32;
+        let exp_ms = base_ms.saturating_mul(1u64 << shift);
+        let capped_ms = exp_ms.min(self.max_delay.as_millis() as u64);
+
+        if self.jitter <= 0.0 || capped_ms == 0 {
+            return Duration::from_millis(capped_ms);
+        }
+
+        let jitter_span_ms = ((capped_ms as f64) * self.jitter) as u64;
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .subsec_nanos() as u64;
+        Duration::from_millis(capped_ms + nanos % jitter_span_ms.max(1))
+    }
+}
+
+// Rust equivalent of NamedWeightUpdateRequest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedWeightUpdateRequest {
+    pub name: String,
+    pub dtype: String,
+    pub shape: Vec<usize>,  // usize is more idiomatic than i32 for sizes
+    pub extras: Option<HashMap<String, serde_json::Value>>,
+    // sha256 (or similar) over the serialized tensor payload, checked against
+    // each engine's post-write digest so a corrupted transfer to any one
+    // tp-sharded replica surfaces as an error instead of a silent mismatch.
+    pub checksum: Option<String>,
+    // Monotonically increasing counter for the weight snapshot this request
+    // belongs to. `InferenceEngineClient::update_named_weight` rejects a
+    // request whose `weight_version` doesn't advance the client's last
+    // accepted version, so a stale or reordered update can't land after a
+    // newer one and leave replicas on different policy snapshots.
+    pub weight_version: u64,
+}
+
+// Custom error type for our inference engine
+#[derive(Debug, thiserror::Error)]
+pub enum InferenceError {
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+    #[error("Engine communication error: {0}")]
+    CommunicationError(String),
+    #[error("Timeout after {seconds}s")]
+    Timeout { seconds: u64 },
+    #[error("Engine not available")]
+    EngineUnavailable,
+    #[error("{0}")]
+    WithContext(ContextualError),
+}
+
+/// A single `key: value` frame attached to an `InferenceError` as it
+/// propagates up through a call site, e.g. `("engine_idx", "2")`.
+#[derive(Debug, Clone)]
+pub struct ContextFrame {
+    pub key: String,
+    pub value: String,
+}
+
+/// An [`InferenceError`] plus the stack of contextual frames attached to it
+/// as it propagated, innermost frame first.
+#[derive(Debug)]
+pub struct ContextualError {
+    pub source: Box<InferenceError>,
+    pub frames: Vec<ContextFrame>,
+}
+
+impl std::fmt::Display for ContextualError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)?;
+        for frame in &self.frames {
+            write!(f, "\n  while {}: {}", frame.key, frame.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Attaches a `key: value` context frame to a failing
+/// `Result<_, InferenceError>` as it propagates up through a call site.
+pub trait InferenceErrorContext<T> {
+    fn context(self, key: &str, value: impl std::fmt::Display) -> Result<T, InferenceError>;
+}
+
+impl<T> InferenceErrorContext<T> for Result<T, InferenceError> {
+    fn context(self, key: &str, value: impl std::fmt::Display) -> Result<T, InferenceError> {
+        self.map_err(|err| {
+            let frame = ContextFrame {
+                key: key.to_string(),
+                value: value.to_string(),
+            };
+            match err {
+                InferenceError::WithContext(mut ctx) => {
+                    ctx.frames.push(frame);
+                    InferenceError::WithContext(ctx)
+                }
+                other => InferenceError::WithContext(ContextualError {
+                    source: Box::new(other),
+                    frames: vec![frame],
+                }),
+            }
+        })
+    }
+}
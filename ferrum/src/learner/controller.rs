@@ -0,0 +1,239 @@
+use std::time::Duration;
+
+use tokio::sync::{mpsc, watch};
+
+use super::errors::LearnerErrorContext;
+use super::traits::Learner;
+use crate::env::types::TrajectoryShard;
+
+/// A cheap, cloneable handle to a [`TrajectoryController`] worker.
+///
+/// Many rollout tasks can hold one of these and share a single learner
+/// connection: `send` buffers a shard without blocking the rollout, and
+/// `recv_version` awaits the next `(version, checkpoint_uri)` the worker
+/// picked up from the learner, decoupling rollout producers from
+/// learner-update latency.
+pub struct TrajectoryController<O, A> {
+    shards: mpsc::Sender<TrajectoryShard<O, A>>,
+    versions: watch::Receiver<(u64, String)>,
+}
+
+impl<O, A> Clone for TrajectoryController<O, A> {
+    fn clone(&self) -> Self {
+        Self {
+            shards: self.shards.clone(),
+            versions: self.versions.clone(),
+        }
+    }
+}
+
+impl<O, A> TrajectoryController<O, A>
+where
+    O: Send + 'static,
+    A: Send + 'static,
+{
+    /// Spawns the background worker that owns `learner`, and returns a handle
+    /// to it. The worker batches shards buffered since the last tick of
+    /// `batch_interval` before handing them to `Learner::submit`, and polls
+    /// `Learner::update` on the same tick for a new published version.
+    pub fn spawn<L>(learner: L, buffer: usize, batch_interval: Duration) -> Self
+    where
+        L: Learner<O, A> + Send + 'static,
+    {
+        let (shards_tx, shards_rx) = mpsc::channel(buffer);
+        let (versions_tx, versions_rx) = watch::channel((0, String::new()));
+
+        tokio::spawn(run(learner, shards_rx, versions_tx, batch_interval));
+
+        Self {
+            shards: shards_tx,
+            versions: versions_rx,
+        }
+    }
+
+    /// Buffers `shard` for the worker's next batched `Learner::submit`.
+    ///
+    /// Non-blocking: if the worker's queue is full, the shard is dropped
+    /// rather than stalling the rollout task that produced it.
+    pub fn send(&self, shard: TrajectoryShard<O, A>) {
+        let _ = self.shards.try_send(shard);
+    }
+
+    /// Awaits the next `(version, checkpoint_uri)` the worker picked up from
+    /// `Learner::update`.
+    pub async fn recv_version(&mut self) -> (u64, String) {
+        // A closed sender (worker task gone) just means we keep returning the
+        // last known version, same as a coordinator that stopped publishing.
+        let _ = self.versions.changed().await;
+        self.versions.borrow().clone()
+    }
+}
+
+/// The worker loop backing every clone of a [`TrajectoryController`]: drains
+/// buffered shards into `learner.submit` and polls `learner.update` once per
+/// `batch_interval` tick, running until every handle has been dropped.
+async fn run<O, A, L>(
+    mut learner: L,
+    mut shards: mpsc::Receiver<TrajectoryShard<O, A>>,
+    versions: watch::Sender<(u64, String)>,
+    batch_interval: Duration,
+) where
+    L: Learner<O, A>,
+    O: Send + 'static,
+    A: Send + 'static,
+{
+    let mut buffer = Vec::new();
+    let mut ticker = tokio::time::interval(batch_interval);
+
+    loop {
+        tokio::select! {
+            maybe_shard = shards.recv() => {
+                match maybe_shard {
+                    Some(shard) => buffer.push(shard),
+                    None => break,
+                }
+            }
+            _ = ticker.tick() => {
+                flush(&mut learner, &mut buffer).await;
+                poll_version(&mut learner, &versions).await;
+            }
+        }
+    }
+
+    flush(&mut learner, &mut buffer).await;
+}
+
+async fn flush<O, A, L>(learner: &mut L, buffer: &mut Vec<TrajectoryShard<O, A>>)
+where
+    L: Learner<O, A>,
+{
+    if buffer.is_empty() {
+        return;
+    }
+    let batch = std::mem::take(buffer);
+    let batch_len = batch.len();
+    if let Err(err) = learner.submit(batch).await.context("batch_len", batch_len) {
+        tracing::warn!(%err, "trajectory batch submit failed");
+    }
+}
+
+async fn poll_version<O, A, L>(learner: &mut L, versions: &watch::Sender<(u64, String)>)
+where
+    L: Learner<O, A>,
+{
+    let last_version = versions.borrow().0;
+    match learner.update().await.context("last_version", last_version) {
+        Ok(version) => {
+            let _ = versions.send(version);
+        }
+        Err(err) => {
+            tracing::warn!(%err, "learner update poll failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    use crate::inference::{CommConfig, InferResponse, InferenceClient};
+    use crate::inference::errors::InferenceError;
+    use crate::learner::errors::LearnerError;
+
+    #[derive(Clone)]
+    struct NoopClient;
+
+    #[async_trait]
+    impl InferenceClient<u8, u8> for NoopClient {
+        async fn infer(
+            &self,
+            _version_id: u64,
+            _obs: Vec<u8>,
+        ) -> Result<InferResponse<u8>, InferenceError> {
+            Ok(InferResponse {
+                actions: Vec::new(),
+                logprobs: Vec::new(),
+            })
+        }
+
+        async fn init_communication(&mut self, _config: CommConfig) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn update_weights(
+            &mut self,
+            _checkpoint_uri: String,
+            _version_id: u64,
+        ) -> Result<(), InferenceError> {
+            Ok(())
+        }
+    }
+
+    /// A `Learner` whose `submit` forwards every batch it's handed onto a
+    /// channel the test can inspect, and whose `update` publishes a fresh,
+    /// strictly increasing version on every poll.
+    struct RecordingLearner {
+        submitted: mpsc::UnboundedSender<Vec<TrajectoryShard<u8, u8>>>,
+        next_version: u64,
+    }
+
+    #[async_trait]
+    impl Learner<u8, u8> for RecordingLearner {
+        fn client(&self) -> impl InferenceClient<u8, u8> {
+            NoopClient
+        }
+
+        async fn submit(&mut self, shards: Vec<TrajectoryShard<u8, u8>>) -> Result<(), LearnerError> {
+            let _ = self.submitted.send(shards);
+            Ok(())
+        }
+
+        async fn update(&mut self) -> Result<(u64, String), LearnerError> {
+            self.next_version += 1;
+            Ok((self.next_version, format!("s3://checkpoint-{}", self.next_version)))
+        }
+    }
+
+    fn shard(id: &str) -> TrajectoryShard<u8, u8> {
+        TrajectoryShard {
+            id: id.to_string(),
+            steps: Vec::new(),
+            version: 0,
+            rollout_probs: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_buffers_shards_until_the_next_tick_flush() {
+        let (submitted_tx, mut submitted_rx) = mpsc::unbounded_channel();
+        let learner = RecordingLearner {
+            submitted: submitted_tx,
+            next_version: 0,
+        };
+        let controller = TrajectoryController::spawn(learner, 8, Duration::from_millis(10));
+
+        controller.send(shard("a"));
+        controller.send(shard("b"));
+
+        let batch = submitted_rx.recv().await.unwrap();
+        let ids: Vec<_> = batch.into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_recv_version_observes_versions_published_by_learner_update() {
+        let (submitted_tx, _submitted_rx) = mpsc::unbounded_channel();
+        let learner = RecordingLearner {
+            submitted: submitted_tx,
+            next_version: 0,
+        };
+        let mut controller = TrajectoryController::spawn(learner, 8, Duration::from_millis(5));
+
+        let (version, checkpoint_uri) = controller.recv_version().await;
+
+        assert_eq!(version, 1);
+        assert_eq!(checkpoint_uri, "s3://checkpoint-1");
+    }
+}
+
@@ -4,4 +4,59 @@ use thiserror::Error;
 pub enum LearnerError {
     #[error("Learner error: {0}")]
     LearnerError(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("{0}")]
+    WithContext(ContextualError),
+}
+
+/// A single `key: value` frame attached to a `LearnerError` as it propagates
+/// up through a call site, e.g. `("checkpoint_uri", "s3://...")`.
+#[derive(Debug, Clone)]
+pub struct ContextFrame {
+    pub key: String,
+    pub value: String,
+}
+
+/// A [`LearnerError`] plus the stack of contextual frames attached to it as
+/// it propagated, innermost frame first.
+#[derive(Debug)]
+pub struct ContextualError {
+    pub source: Box<LearnerError>,
+    pub frames: Vec<ContextFrame>,
+}
+
+impl std::fmt::Display for ContextualError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)?;
+        for frame in &self.frames {
+            write!(f, "\n  while {}: {}", frame.key, frame.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Attaches a `key: value` context frame to a failing `Result<_, LearnerError>`
+/// as it propagates up through a call site.
+pub trait LearnerErrorContext<T> {
+    fn context(self, key: &str, value: impl std::fmt::Display) -> Result<T, LearnerError>;
+}
+
+impl<T> LearnerErrorContext<T> for Result<T, LearnerError> {
+    fn context(self, key: &str, value: impl std::fmt::Display) -> Result<T, LearnerError> {
+        self.map_err(|err| {
+            let frame = ContextFrame {
+                key: key.to_string(),
+                value: value.to_string(),
+            };
+            match err {
+                LearnerError::WithContext(mut ctx) => {
+                    ctx.frames.push(frame);
+                    LearnerError::WithContext(ctx)
+                }
+                other => LearnerError::WithContext(ContextualError {
+                    source: Box::new(other),
+                    frames: vec![frame],
+                }),
+            }
+        })
+    }
 }
@@ -1,6 +1,21 @@
+use std::sync::Arc;
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+/// Which layer of the runtime an [`Error`] originated from.
+///
+/// This lets callers match on "the channel broke" vs. "the method rejected
+/// my payload" without string-sniffing `Display` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The request never reached (or a response never came back from) the
+    /// other side: a dropped channel, a full queue, a canceled task.
+    Transport,
+    /// The request reached the service and failed there: a bad payload, a
+    /// (de)serialization failure, or an application-level rejection.
+    Call,
+}
+
+#[derive(Debug, Error, Clone)]
 pub enum Error {
     #[error("task canceled")]
     Canceled,
@@ -14,6 +29,13 @@ pub enum Error {
     #[error("service unavailable")]
     ServiceUnavailable,
 
+    /// The underlying channel closed for a reason we can point to, e.g. a
+    /// `ServiceRunner` task panicked or gave up after a fatal error. Wrapped
+    /// in `Arc` so the one captured cause can be shared across every pending
+    /// `oneshot` receiver it's broadcast to.
+    #[error("channel closed: {0}")]
+    Closed(Arc<dyn std::error::Error + Send + Sync>),
+
     #[error("serialization error: {0}")]
     Serialize(String),
 
@@ -24,8 +46,69 @@ pub enum Error {
     Internal(String),
 }
 
+impl Error {
+    /// Which layer this error belongs to: [`ErrorKind::Transport`] for
+    /// channel/delivery failures, [`ErrorKind::Call`] for failures the
+    /// service itself produced.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Canceled
+            | Error::Timeout
+            | Error::QueueFull
+            | Error::ServiceUnavailable
+            | Error::Closed(_) => ErrorKind::Transport,
+            Error::Serialize(_) | Error::Deserialize(_) | Error::Internal(_) => ErrorKind::Call,
+        }
+    }
+}
+
 impl From<tokio::sync::oneshot::error::RecvError> for Error {
     fn from(_: tokio::sync::oneshot::error::RecvError) -> Self {
         Error::Canceled
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_classifies_transport_vs_call_errors() {
+        assert_eq!(Error::Canceled.kind(), ErrorKind::Transport);
+        assert_eq!(Error::Timeout.kind(), ErrorKind::Transport);
+        assert_eq!(Error::QueueFull.kind(), ErrorKind::Transport);
+        assert_eq!(Error::ServiceUnavailable.kind(), ErrorKind::Transport);
+        assert_eq!(
+            Error::Internal("boom".to_string()).kind(),
+            ErrorKind::Call
+        );
+        assert_eq!(
+            Error::Serialize("boom".to_string()).kind(),
+            ErrorKind::Call
+        );
+        assert_eq!(
+            Error::Deserialize("boom".to_string()).kind(),
+            ErrorKind::Call
+        );
+    }
+
+    #[test]
+    fn test_closed_clone_shares_the_same_underlying_cause() {
+        let closed = Error::Closed(Arc::new(Error::Internal("runner panicked".to_string())));
+        let cloned = closed.clone();
+
+        match (&closed, &cloned) {
+            (Error::Closed(a), Error::Closed(b)) => assert!(Arc::ptr_eq(a, b)),
+            _ => panic!("expected both to be Error::Closed"),
+        }
+        assert_eq!(closed.to_string(), cloned.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_recv_error_converts_to_canceled() {
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        drop(tx);
+        let recv_err = rx.await.unwrap_err();
+        assert!(matches!(Error::from(recv_err), Error::Canceled));
+    }
+}
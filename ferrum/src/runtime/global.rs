@@ -1,17 +1,52 @@
-use crate::runtime::{LocalScheduler, Scheduler, Task, TaskHandle};
+use crate::runtime::{LocalScheduler, Scheduler, Task, TaskHandle, ThrottlingScheduler};
 use std::sync::OnceLock;
+use std::time::Duration;
 
 // Use concrete type instead of dyn Scheduler
-static GLOBAL_SCHEDULER: OnceLock<LocalScheduler> = OnceLock::new();
+static GLOBAL_SCHEDULER: OnceLock<RuntimeScheduler> = OnceLock::new();
+static SCHEDULER_CONFIG: OnceLock<SchedulerConfig> = OnceLock::new();
 
 #[derive(Debug, Clone)]
 pub struct SchedulerConfig {
     pub workers: Option<usize>,
+    /// When set, `submit`ted tasks are batched onto a run-queue and polled
+    /// once per tick of this interval instead of being spawned immediately,
+    /// amortizing wakeup/poll overhead across many tiny tasks (e.g. RL
+    /// rollouts submitting one task per `env.step`). `None` preserves
+    /// today's immediate-spawn behavior.
+    pub throttle: Option<Duration>,
 }
 
 impl Default for SchedulerConfig {
     fn default() -> Self {
-        Self { workers: None }
+        Self {
+            workers: None,
+            throttle: None,
+        }
+    }
+}
+
+/// Either of the scheduler implementations `init_with_config` can select,
+/// unified behind one type so `GLOBAL_SCHEDULER` doesn't need `dyn Scheduler`.
+enum RuntimeScheduler {
+    Local(LocalScheduler),
+    Throttled(ThrottlingScheduler),
+}
+
+impl Scheduler for RuntimeScheduler {
+    type Handle<T>
+        = TaskHandle<T>
+    where
+        T: Send + 'static;
+
+    fn submit<T>(&self, task: T) -> Self::Handle<T::Output>
+    where
+        T: Task + 'static,
+    {
+        match self {
+            RuntimeScheduler::Local(s) => s.submit(task),
+            RuntimeScheduler::Throttled(s) => s.submit(task),
+        }
     }
 }
 
@@ -19,15 +54,22 @@ pub fn init() -> Result<(), String> {
     init_with_config(SchedulerConfig::default())
 }
 
-pub fn init_with_config(_config: SchedulerConfig) -> Result<(), String> {
-    // For now, just ensure scheduler gets initialized
-    // Later we can use config when creating different scheduler types
+pub fn init_with_config(config: SchedulerConfig) -> Result<(), String> {
+    // First call wins: the scheduler is a `OnceLock`, so once it's running
+    // its mode can't change out from under in-flight tasks.
+    let _ = SCHEDULER_CONFIG.set(config);
     let _ = get_or_init_scheduler();
     Ok(())
 }
 
-pub fn get_or_init_scheduler() -> &'static LocalScheduler {
-    GLOBAL_SCHEDULER.get_or_init(|| LocalScheduler::new())
+fn get_or_init_scheduler() -> &'static RuntimeScheduler {
+    GLOBAL_SCHEDULER.get_or_init(|| {
+        let config = SCHEDULER_CONFIG.get_or_init(SchedulerConfig::default);
+        match config.throttle {
+            Some(interval) => RuntimeScheduler::Throttled(ThrottlingScheduler::new(interval)),
+            None => RuntimeScheduler::Local(LocalScheduler::new()),
+        }
+    })
 }
 
 // This is what the macro will call
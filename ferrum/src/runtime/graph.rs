@@ -0,0 +1,259 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use uuid::Uuid;
+
+pub type NodeId = Uuid;
+
+type AnyOutput = Arc<dyn Any + Send + Sync>;
+type BoxedRun = Box<dyn FnOnce(Vec<AnyOutput>) -> Pin<Box<dyn Future<Output = AnyOutput> + Send>> + Send>;
+
+/// Errors produced while building or running a `TaskGraph`.
+#[derive(Debug, thiserror::Error)]
+pub enum GraphError {
+    #[error("dependency cycle detected in task graph")]
+    Cycle,
+}
+
+struct Node {
+    dependencies: Vec<NodeId>,
+    run: BoxedRun,
+}
+
+/// A DAG of tasks where a node can depend on the outputs of upstream nodes.
+///
+/// Running the graph schedules it with Kahn's algorithm: every node whose
+/// dependencies have all completed is launched concurrently, and completing a
+/// node feeds its output to dependents whose remaining in-degree hits zero.
+/// Cycles are detected up front via [`TaskGraph::run`] instead of deadlocking.
+#[derive(Default)]
+pub struct TaskGraph {
+    nodes: HashMap<NodeId, Node>,
+}
+
+impl TaskGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node with no dependencies.
+    pub fn add_task<F, Fut, T>(&mut self, task: F) -> NodeId
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+        T: Send + Sync + 'static,
+    {
+        self.add_node(Vec::new(), move |_deps| {
+            Box::pin(async move { Arc::new(task().await) as AnyOutput })
+        })
+    }
+
+    /// Add a node that depends on `dependency`'s output. `f` receives the
+    /// upstream output wrapped in an `Arc` (shared, since more than one
+    /// dependent may read the same upstream result).
+    pub fn add_dependent<U, F, Fut, T>(&mut self, dependency: NodeId, f: F) -> NodeId
+    where
+        U: Send + Sync + 'static,
+        F: FnOnce(Arc<U>) -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+        T: Send + Sync + 'static,
+    {
+        self.add_node(vec![dependency], move |mut deps| {
+            let input = deps
+                .remove(0)
+                .downcast::<U>()
+                .unwrap_or_else(|_| panic!("task graph dependency type mismatch"));
+            Box::pin(async move { Arc::new(f(input).await) as AnyOutput })
+        })
+    }
+
+    fn add_node(
+        &mut self,
+        dependencies: Vec<NodeId>,
+        run: impl FnOnce(Vec<AnyOutput>) -> Pin<Box<dyn Future<Output = AnyOutput> + Send>>
+        + Send
+        + 'static,
+    ) -> NodeId {
+        let id = Uuid::new_v4();
+        self.nodes.insert(
+            id,
+            Node {
+                dependencies,
+                run: Box::new(run),
+            },
+        );
+        id
+    }
+
+    /// Compute in-degrees and a topological order, failing if the graph has a
+    /// cycle. Used purely for up-front validation before `run` starts
+    /// launching anything.
+    fn validate_acyclic(&self) -> Result<(), GraphError> {
+        let mut in_degree: HashMap<NodeId, usize> =
+            self.nodes.keys().map(|id| (*id, 0)).collect();
+        let mut dependents: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+
+        for (&id, node) in &self.nodes {
+            *in_degree.get_mut(&id).unwrap() = node.dependencies.len();
+            for dep in &node.dependencies {
+                dependents.entry(*dep).or_default().push(id);
+            }
+        }
+
+        let mut ready: Vec<NodeId> = in_degree
+            .iter()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        let mut visited = 0;
+
+        while let Some(id) = ready.pop() {
+            visited += 1;
+            for &dependent in dependents.get(&id).into_iter().flatten() {
+                let deg = in_degree.get_mut(&dependent).unwrap();
+                *deg -= 1;
+                if *deg == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+
+        if visited == self.nodes.len() {
+            Ok(())
+        } else {
+            Err(GraphError::Cycle)
+        }
+    }
+
+    /// Run every node to completion and return each node's output keyed by
+    /// `NodeId`.
+    pub async fn run(self) -> Result<HashMap<NodeId, AnyOutput>, GraphError> {
+        self.validate_acyclic()?;
+
+        let mut dependents: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        let mut in_degree: HashMap<NodeId, usize> = HashMap::new();
+        for (&id, node) in &self.nodes {
+            in_degree.insert(id, node.dependencies.len());
+            for dep in &node.dependencies {
+                dependents.entry(*dep).or_default().push(id);
+            }
+        }
+
+        let mut pending = self.nodes;
+        let mut outputs: HashMap<NodeId, AnyOutput> = HashMap::new();
+        let mut in_flight = FuturesUnordered::new();
+        let mut ready: Vec<NodeId> = in_degree
+            .iter()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(id, _)| *id)
+            .collect();
+
+        loop {
+            for id in ready.drain(..) {
+                let node = pending.remove(&id).expect("node launched twice");
+                let deps = node
+                    .dependencies
+                    .iter()
+                    .map(|dep_id| outputs[dep_id].clone())
+                    .collect();
+                let fut = (node.run)(deps);
+                in_flight.push(async move { (id, fut.await) });
+            }
+
+            let Some((finished_id, output)) = in_flight.next().await else {
+                break;
+            };
+            outputs.insert(finished_id, output);
+
+            for &dependent in dependents.get(&finished_id).into_iter().flatten() {
+                let deg = in_degree.get_mut(&dependent).unwrap();
+                *deg -= 1;
+                if *deg == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output_i32(outputs: &HashMap<NodeId, AnyOutput>, id: NodeId) -> i32 {
+        *outputs[&id].downcast_ref::<i32>().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_run_executes_a_linear_chain_in_order() {
+        let mut graph = TaskGraph::new();
+        let a = graph.add_task(|| async { 1 });
+        let b = graph.add_dependent(a, |a: Arc<i32>| async move { *a + 1 });
+        let c = graph.add_dependent(b, |b: Arc<i32>| async move { *b + 1 });
+
+        let outputs = graph.run().await.unwrap();
+
+        assert_eq!(output_i32(&outputs, a), 1);
+        assert_eq!(output_i32(&outputs, b), 2);
+        assert_eq!(output_i32(&outputs, c), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_resolves_a_fan_out_fan_in_diamond() {
+        let mut graph = TaskGraph::new();
+        let source = graph.add_task(|| async { 10 });
+        let double = graph.add_dependent(source, |n: Arc<i32>| async move { *n * 2 });
+        let square = graph.add_dependent(source, |n: Arc<i32>| async move { *n * *n });
+
+        // `add_dependent` only takes a single upstream, so the fan-in join
+        // node is built directly through `add_node` with both branches as
+        // dependencies.
+        let join = graph.add_node(vec![double, square], |mut deps| {
+            Box::pin(async move {
+                let square = *deps.remove(1).downcast::<i32>().unwrap();
+                let double = *deps.remove(0).downcast::<i32>().unwrap();
+                Arc::new(double + square) as AnyOutput
+            })
+        });
+
+        let outputs = graph.run().await.unwrap();
+
+        assert_eq!(output_i32(&outputs, source), 10);
+        assert_eq!(output_i32(&outputs, double), 20);
+        assert_eq!(output_i32(&outputs, square), 100);
+        assert_eq!(output_i32(&outputs, join), 120);
+    }
+
+    #[tokio::test]
+    async fn test_validate_acyclic_rejects_a_cycle_instead_of_deadlocking() {
+        let mut graph = TaskGraph::new();
+        // `add_task`/`add_dependent` can only reference a dependency whose
+        // `NodeId` already exists, so a true cycle can't be built through the
+        // public API — construct the two mutually-dependent nodes directly.
+        let a_id = NodeId::new_v4();
+        let b_id = NodeId::new_v4();
+        graph.nodes.insert(
+            a_id,
+            Node {
+                dependencies: vec![b_id],
+                run: Box::new(|_deps| Box::pin(async { Arc::new(()) as AnyOutput })),
+            },
+        );
+        graph.nodes.insert(
+            b_id,
+            Node {
+                dependencies: vec![a_id],
+                run: Box::new(|_deps| Box::pin(async { Arc::new(()) as AnyOutput })),
+            },
+        );
+
+        assert!(matches!(graph.validate_acyclic(), Err(GraphError::Cycle)));
+    }
+}
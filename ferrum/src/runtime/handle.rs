@@ -8,6 +8,7 @@ use std::time::Duration;
 use crate::runtime::error::Error;
 use crate::runtime::result_source::{LocalResultSource, ResultSource};
 use pin_project_lite::pin_project;
+use tracing::Instrument;
 use uuid::Uuid;
 
 pin_project! {
@@ -30,6 +31,11 @@ pin_project! {
         id: Uuid,
         #[pin]
         source: S,
+        // Captured at construction time (i.e. at `submit`/`ServiceAddress::call`
+        // time), nested under whatever span was current at the caller. Entered
+        // around `source.poll`, so the task's execution shows up under the
+        // caller's trace even once it's driven from an unrelated task/thread.
+        span: tracing::Span,
         _phantom: PhantomData<T>,
     }
 }
@@ -55,9 +61,12 @@ where
     S: ResultSource<T>,
 {
     pub(crate) fn new(id: Uuid, source: S) -> Self {
+        let parent = tracing::Span::current();
+        let span = tracing::info_span!(parent: &parent, "task", task_id = %id);
         Self {
             id,
             source,
+            span,
             _phantom: PhantomData,
         }
     }
@@ -77,17 +86,27 @@ where
         U: Send + 'static,
         F: FnOnce(T) -> U + Send + 'static,
     {
-        async move { self.await.map(f) }
+        let id = self.id;
+        let child = tracing::info_span!(parent: &self.span, "task_map", task_id = %id);
+        async move { self.await.map(f) }.instrument(child)
     }
 
     /// Add a timeout to this task handle
     pub fn timeout(self, dur: Duration) -> impl Future<Output = Result<T, Error>> + Send {
+        let id = self.id;
+        let child = tracing::info_span!(
+            parent: &self.span,
+            "task_timeout",
+            task_id = %id,
+            timeout_ms = dur.as_millis() as u64,
+        );
         async move {
             match tokio::time::timeout(dur, self).await {
                 Ok(r) => r,
                 Err(_) => Err(Error::Timeout),
             }
         }
+        .instrument(child)
     }
 }
 
@@ -100,9 +119,145 @@ where
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         // Safe pin projection using pin-project-lite
-        self.project().source.poll(cx)
+        let this = self.project();
+        let _enter = this.span.enter();
+        this.source.poll(cx)
     }
 }
 
 // Type alias for the common local case
 pub type LocalTaskHandle<T> = TaskHandle<T, LocalResultSource<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use tracing::span::{Attributes, Current, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    struct SpanInfo {
+        name: &'static str,
+        parent: Option<u64>,
+        metadata: &'static Metadata<'static>,
+    }
+
+    thread_local! {
+        static CURRENT_STACK: std::cell::RefCell<Vec<Id>> = std::cell::RefCell::new(Vec::new());
+    }
+
+    /// A minimal `Subscriber` that records each span's name and parent, so a
+    /// test can assert one span is nested under another without pulling in
+    /// `tracing-subscriber` as a dev-dependency.
+    struct RecordingSubscriber {
+        next_id: AtomicU64,
+        spans: std::sync::Mutex<HashMap<u64, SpanInfo>>,
+    }
+
+    impl RecordingSubscriber {
+        fn new() -> Self {
+            Self {
+                next_id: AtomicU64::new(1),
+                spans: std::sync::Mutex::new(HashMap::new()),
+            }
+        }
+
+        /// The name of the span `child`'s parent was recorded under, if any.
+        fn parent_name_of(&self, child: &str) -> Option<&'static str> {
+            let spans = self.spans.lock().unwrap();
+            let info = spans.values().find(|info| info.name == child)?;
+            info.parent.and_then(|id| spans.get(&id)).map(|p| p.name)
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+            let parent = if let Some(explicit) = attrs.parent() {
+                Some(explicit.into_u64())
+            } else if attrs.is_contextual() {
+                CURRENT_STACK.with(|stack| stack.borrow().last().map(|id| id.into_u64()))
+            } else {
+                None
+            };
+            self.spans.lock().unwrap().insert(
+                id,
+                SpanInfo {
+                    name: attrs.metadata().name(),
+                    parent,
+                    metadata: attrs.metadata(),
+                },
+            );
+            Id::from_u64(id)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            CURRENT_STACK.with(|stack| stack.borrow_mut().push(span.clone()));
+        }
+
+        fn exit(&self, span: &Id) {
+            CURRENT_STACK.with(|stack| {
+                let mut stack = stack.borrow_mut();
+                if stack.last() == Some(span) {
+                    stack.pop();
+                }
+            });
+        }
+
+        fn current_span(&self) -> Current {
+            CURRENT_STACK.with(|stack| match stack.borrow().last() {
+                Some(id) => match self.spans.lock().unwrap().get(&id.into_u64()) {
+                    Some(info) => Current::new(id.clone(), info.metadata),
+                    None => Current::none(),
+                },
+                None => Current::none(),
+            })
+        }
+    }
+
+    fn ready_handle(value: u64) -> LocalTaskHandle<u64> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let _ = tx.send(Ok(value));
+        TaskHandle::new(Uuid::new_v4(), LocalResultSource::new(rx))
+    }
+
+    #[tokio::test]
+    async fn test_map_span_nests_under_the_task_span_captured_at_construction() {
+        let subscriber = Arc::new(RecordingSubscriber::new());
+        let dispatch = tracing::Dispatch::new(subscriber.clone());
+        let _guard = tracing::dispatcher::set_default(&dispatch);
+
+        let root = tracing::info_span!("caller");
+        let handle = root.in_scope(|| ready_handle(41));
+
+        let mapped = handle.map(|v| v + 1);
+        assert_eq!(mapped.await.unwrap(), 42);
+
+        assert_eq!(subscriber.parent_name_of("task_map"), Some("task"));
+        assert_eq!(subscriber.parent_name_of("task"), Some("caller"));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_span_nests_under_the_task_span_captured_at_construction() {
+        let subscriber = Arc::new(RecordingSubscriber::new());
+        let dispatch = tracing::Dispatch::new(subscriber.clone());
+        let _guard = tracing::dispatcher::set_default(&dispatch);
+
+        let root = tracing::info_span!("caller");
+        let handle = root.in_scope(|| ready_handle(1));
+
+        let timed = handle.timeout(Duration::from_secs(5));
+        assert_eq!(timed.await.unwrap(), 1);
+
+        assert_eq!(subscriber.parent_name_of("task_timeout"), Some("task"));
+    }
+}
@@ -1,7 +1,9 @@
 pub mod codec;
 pub mod error;
 pub mod global;
+pub mod graph;
 pub mod handle;
+pub mod remote;
 pub mod result_source;
 pub mod scheduler;
 pub mod service;
@@ -9,7 +11,9 @@ pub mod task;
 
 pub use error::Error;
 pub use global::{SchedulerConfig, init, init_with_config, submit};
+pub use graph::{GraphError, TaskGraph};
 pub use handle::TaskHandle;
+pub use remote::{RemoteResultSource, RemoteServiceAddress};
 pub use result_source::{LocalResultSource, ResultSource};
-pub use scheduler::{LocalScheduler, Scheduler};
+pub use scheduler::{LocalScheduler, Scheduler, ThrottlingScheduler};
 pub use task::Task;
@@ -0,0 +1,334 @@
+//! Remote (QUIC) transport for `TaskHandle` and `Service`.
+//!
+//! `ResultSource<T>` already abstracts result retrieval behind
+//! `LocalResultSource`, and `control::RunInfo` carries `inference_addrs`/
+//! `learner_addr` strings describing where those services live — but driving
+//! a `Service` or awaiting a `TaskHandle` across a network boundary needs a
+//! transport. This module adds one: a `method` + `payload` goes out on a
+//! freshly opened bidirectional QUIC stream as a single length-delimited,
+//! JSON-framed message, and the response bytes come back the same way and
+//! feed the same deserialization path as `JsonResultSource`. A coordinator
+//! node can use [`serve`] to expose a local `ServiceAddress` to callers on
+//! other hosts, turning the actor runtime into a distributed RPC mesh.
+
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+use quinn::{Connection, Endpoint, RecvStream, SendStream};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+use crate::runtime::codec::JsonResultSource;
+use crate::runtime::error::Error;
+use crate::runtime::handle::TaskHandle;
+use crate::runtime::result_source::ResultSource;
+use crate::runtime::service::ServiceAddress;
+
+/// The wire message sent on a freshly opened bidirectional QUIC stream:
+/// identical in shape to `service::ServiceRequest`, minus the `respond_to`
+/// channel, which doesn't make sense across a network boundary.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteCallRequest {
+    method: String,
+    payload: Vec<u8>,
+}
+
+/// The wire message sent back on the same stream. `Err` carries the
+/// rendered `Display` of the remote `Error`, since `Error`'s `Closed` variant
+/// can't itself cross the wire.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteCallResponse {
+    result: Result<Vec<u8>, String>,
+}
+
+async fn read_framed(stream: &mut RecvStream) -> Result<Vec<u8>, Error> {
+    let mut len_buf = [0u8; 4];
+    stream
+        .read_exact(&mut len_buf)
+        .await
+        .map_err(|e| Error::Closed(Arc::new(e)))?;
+    let len = u32::from_be_bytes(len_buf) as usize;
+    let mut buf = vec![0u8; len];
+    stream
+        .read_exact(&mut buf)
+        .await
+        .map_err(|e| Error::Closed(Arc::new(e)))?;
+    Ok(buf)
+}
+
+async fn write_framed(stream: &mut SendStream, bytes: &[u8]) -> Result<(), Error> {
+    stream
+        .write_all(&(bytes.len() as u32).to_be_bytes())
+        .await
+        .map_err(|e| Error::Closed(Arc::new(e)))?;
+    stream
+        .write_all(bytes)
+        .await
+        .map_err(|e| Error::Closed(Arc::new(e)))?;
+    Ok(())
+}
+
+pin_project! {
+    /// A `ResultSource` whose value is delivered by a background task driving
+    /// a QUIC bidirectional stream instead of a purely local `oneshot` send.
+    ///
+    /// Once the round trip lands the response bytes in its inner channel,
+    /// this deserializes exactly like [`JsonResultSource`] — remote and
+    /// local callers share one decoding path.
+    pub struct RemoteResultSource<T> {
+        #[pin]
+        inner: JsonResultSource<T>,
+        _phantom: PhantomData<T>,
+    }
+}
+
+impl<T> RemoteResultSource<T>
+where
+    T: Send + 'static,
+{
+    fn from_receiver(rx: oneshot::Receiver<Result<Vec<u8>, Error>>) -> Self {
+        Self {
+            inner: JsonResultSource::from_receiver(rx),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> std::future::Future for RemoteResultSource<T>
+where
+    T: Send + 'static + DeserializeOwned,
+{
+    type Output = Result<T, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<T> ResultSource<T> for RemoteResultSource<T> where T: Send + 'static + DeserializeOwned {}
+
+/// A client-side proxy to a `Service` exposed on another host, mirroring
+/// `ServiceAddress::call` over a QUIC connection instead of an in-process
+/// `mpsc` channel.
+#[derive(Clone)]
+pub struct RemoteServiceAddress {
+    connection: Connection,
+}
+
+impl RemoteServiceAddress {
+    /// Opens a QUIC connection to a `Service` exposed via [`serve`].
+    pub async fn connect(
+        endpoint: &Endpoint,
+        addr: std::net::SocketAddr,
+        server_name: &str,
+    ) -> Result<Self, Error> {
+        let connecting = endpoint
+            .connect(addr, server_name)
+            .map_err(|e| Error::Closed(Arc::new(e)))?;
+        let connection = connecting.await.map_err(|e| Error::Closed(Arc::new(e)))?;
+        Ok(Self { connection })
+    }
+
+    /// Enqueues a remote service call and returns a `TaskHandle` to await the
+    /// response, deserialized as `T`.
+    ///
+    /// Unlike `ServiceAddress::call`, there is no local queue to reject into:
+    /// a connection drop or deserialization failure surfaces as the
+    /// `TaskHandle`'s `Error` once awaited.
+    pub fn call<T>(&self, method: &str, payload: Vec<u8>) -> TaskHandle<T, RemoteResultSource<T>>
+    where
+        T: Send + 'static + DeserializeOwned,
+    {
+        let call_id = Uuid::new_v4();
+        let (res_tx, res_rx) = oneshot::channel::<Result<Vec<u8>, Error>>();
+
+        let connection = self.connection.clone();
+        let method = method.to_string();
+        tokio::spawn(async move {
+            let result = call_over_stream(&connection, method, payload).await;
+            let _ = res_tx.send(result);
+        });
+
+        TaskHandle::new(call_id, RemoteResultSource::from_receiver(res_rx))
+    }
+}
+
+async fn call_over_stream(
+    connection: &Connection,
+    method: String,
+    payload: Vec<u8>,
+) -> Result<Vec<u8>, Error> {
+    let (mut send, mut recv) = connection
+        .open_bi()
+        .await
+        .map_err(|e| Error::Closed(Arc::new(e)))?;
+
+    let request = RemoteCallRequest { method, payload };
+    let request_bytes =
+        serde_json::to_vec(&request).map_err(|e| Error::Serialize(e.to_string()))?;
+    write_framed(&mut send, &request_bytes).await?;
+    send.finish().map_err(|e| Error::Closed(Arc::new(e)))?;
+
+    let response_bytes = read_framed(&mut recv).await?;
+    let response: RemoteCallResponse = serde_json::from_slice(&response_bytes)
+        .map_err(|e| Error::Deserialize(e.to_string()))?;
+    response.result.map_err(Error::Internal)
+}
+
+/// Serves `address` over `endpoint`, routing each inbound QUIC bidirectional
+/// stream to one `ServiceAddress::call`. Runs until the endpoint stops
+/// accepting connections (e.g. `endpoint.close()` is called elsewhere).
+///
+/// This is how a coordinator node exposes a local inference/learner
+/// `ServiceRunner` to callers on other hosts.
+pub async fn serve(endpoint: Endpoint, address: ServiceAddress) -> Result<(), Error> {
+    while let Some(incoming) = endpoint.accept().await {
+        let connection = match incoming.await {
+            Ok(connection) => connection,
+            Err(_) => continue,
+        };
+        tokio::spawn(handle_connection(connection, address.clone()));
+    }
+    Ok(())
+}
+
+async fn handle_connection(connection: Connection, address: ServiceAddress) {
+    loop {
+        let (send, recv) = match connection.accept_bi().await {
+            Ok(streams) => streams,
+            Err(_) => return,
+        };
+        tokio::spawn(handle_stream(send, recv, address.clone()));
+    }
+}
+
+async fn handle_stream(mut send: SendStream, mut recv: RecvStream, address: ServiceAddress) {
+    let result = handle_stream_inner(&mut send, &mut recv, address).await;
+    if result.is_err() {
+        // Best-effort: the peer already lost the connection or the framing
+        // broke, and there is no one left to report the error to.
+    }
+}
+
+async fn handle_stream_inner(
+    send: &mut SendStream,
+    recv: &mut RecvStream,
+    address: ServiceAddress,
+) -> Result<(), Error> {
+    let request_bytes = read_framed(recv).await?;
+    let request: RemoteCallRequest =
+        serde_json::from_slice(&request_bytes).map_err(|e| Error::Deserialize(e.to_string()))?;
+
+    let result = address.call(&request.method, request.payload).await;
+    let response = RemoteCallResponse {
+        result: result.map_err(|e| e.to_string()),
+    };
+    let response_bytes =
+        serde_json::to_vec(&response).map_err(|e| Error::Serialize(e.to_string()))?;
+    write_framed(send, &response_bytes).await?;
+    send.finish().map_err(|e| Error::Closed(Arc::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    use crate::runtime::service::{Service, ServiceResult, ServiceRunner};
+
+    /// A self-signed, loopback-only `(Endpoint, Endpoint)` pair for testing
+    /// the QUIC round trip without touching a real certificate store.
+    fn loopback_endpoints() -> (Endpoint, Endpoint, std::net::SocketAddr) {
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
+        let cert_der = cert.cert.der().clone();
+        let key_der = cert.signing_key.serialize_der();
+
+        let server_config = quinn::ServerConfig::with_single_cert(
+            vec![cert_der.clone()],
+            rustls::pki_types::PrivateKeyDer::Pkcs8(key_der.into()),
+        )
+        .unwrap();
+
+        let server =
+            Endpoint::server(server_config, "127.0.0.1:0".parse().unwrap()).unwrap();
+        let server_addr = server.local_addr().unwrap();
+
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(cert_der).unwrap();
+        let client_crypto = rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let client_config = quinn::ClientConfig::new(Arc::new(
+            quinn::crypto::rustls::QuicClientConfig::try_from(client_crypto).unwrap(),
+        ));
+
+        let mut client = Endpoint::client("0.0.0.0:0".parse().unwrap()).unwrap();
+        client.set_default_client_config(client_config);
+
+        (server, client, server_addr)
+    }
+
+    struct EchoService;
+
+    #[async_trait]
+    impl Service for EchoService {
+        async fn call(&mut self, _method: &str, payload: Vec<u8>) -> ServiceResult<Vec<u8>> {
+            Ok(payload)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_call_round_trips_through_a_served_service() {
+        let (server, client, server_addr) = loopback_endpoints();
+        let address = ServiceRunner::spawn(EchoService, 4);
+
+        tokio::spawn(serve(server, address));
+
+        let remote = RemoteServiceAddress::connect(&client, server_addr, "localhost")
+            .await
+            .unwrap();
+
+        let payload = serde_json::to_vec("hello").unwrap();
+        let result: String = remote.call("echo", payload).await.unwrap();
+
+        assert_eq!(result, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_remote_call_fails_with_deserialize_error_on_type_mismatch() {
+        let (server, client, server_addr) = loopback_endpoints();
+        let address = ServiceRunner::spawn(EchoService, 4);
+
+        tokio::spawn(serve(server, address));
+
+        let remote = RemoteServiceAddress::connect(&client, server_addr, "localhost")
+            .await
+            .unwrap();
+
+        // The service echoes back a JSON string, but the caller asks for a
+        // `u64` — the mismatch should surface as `Error::Deserialize` rather
+        // than panicking or hanging.
+        let payload = serde_json::to_vec("not a number").unwrap();
+        let result: Result<u64, Error> = remote.call("echo", payload).await;
+
+        assert!(matches!(result, Err(Error::Deserialize(_))));
+    }
+
+    #[tokio::test]
+    async fn test_remote_call_surfaces_closed_error_when_server_drops_connection() {
+        let (server, client, server_addr) = loopback_endpoints();
+        // Close the server endpoint immediately so the client's stream open
+        // fails instead of ever reaching a `Service`.
+        server.close(0u32.into(), b"shutting down");
+
+        let connect_result = RemoteServiceAddress::connect(&client, server_addr, "localhost").await;
+
+        assert!(matches!(connect_result, Err(Error::Closed(_))));
+    }
+}
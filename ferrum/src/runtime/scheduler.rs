@@ -1,6 +1,15 @@
-use crate::runtime::{Error, handle::TaskHandle, result_source::LocalResultSource, task::Task};
+use crate::runtime::{Error, handle::TaskHandle, result_source::LocalResultSource, task::{Task, TaskId}};
+use futures::future::join_all;
+use futures::poll;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
-use tokio::sync::oneshot;
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
 use uuid::Uuid;
 
 /// A trait for a task scheduler, responsible for executing tasks on a runtime.
@@ -85,3 +94,431 @@ impl Scheduler for LocalScheduler {
         TaskHandle::new(task_id, LocalResultSource::new(receiver))
     }
 }
+
+/// A unit of run-queue work: a task's `call()` future plus the send of its
+/// result into the `oneshot` that backs its `TaskHandle`, erased down to
+/// `Future<Output = ()>` so heterogeneous tasks can share one `FuturesUnordered`.
+type BoxedUnitFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A scheduler that batches `submit`ted tasks onto a run-queue and polls them
+/// once per tick of a fixed interval, instead of spawning a tokio task per
+/// call like [`LocalScheduler`] does.
+///
+/// RL rollouts submit enormous numbers of tiny, short-lived tasks (a single
+/// `env.step`); spawning one tokio task each is wasteful. Batching amortizes
+/// wakeup and poll overhead across every task ready in a given tick, at the
+/// cost of a bounded latency increase (up to one tick interval).
+pub struct ThrottlingScheduler {
+    tx: mpsc::UnboundedSender<BoxedUnitFuture>,
+}
+
+impl ThrottlingScheduler {
+    /// Spawns the background run-queue, ticking every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(run_throttled(rx, interval));
+        Self { tx }
+    }
+}
+
+/// Drains newly submitted tasks into `run_queue` on every tick and polls
+/// every currently-ready future in the queue exactly once; a future that
+/// returns `Pending` simply stays in `run_queue` for the next tick.
+async fn run_throttled(mut rx: mpsc::UnboundedReceiver<BoxedUnitFuture>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    let mut run_queue: FuturesUnordered<BoxedUnitFuture> = FuturesUnordered::new();
+
+    loop {
+        ticker.tick().await;
+
+        while let Ok(fut) = rx.try_recv() {
+            run_queue.push(fut);
+        }
+
+        while matches!(poll!(run_queue.next()), Poll::Ready(Some(()))) {}
+    }
+}
+
+impl Scheduler for ThrottlingScheduler {
+    type Handle<T>
+        = TaskHandle<T, LocalResultSource<T>>
+    where
+        T: Send + 'static;
+
+    fn submit<T>(&self, task: T) -> Self::Handle<T::Output>
+    where
+        T: Task + 'static,
+    {
+        let task_id = Uuid::new_v4();
+        let (sender, receiver) = oneshot::channel::<Result<T::Output, Error>>();
+
+        let work: BoxedUnitFuture = Box::pin(async move {
+            let result = task.call().await;
+            let _ = sender.send(Ok(result));
+        });
+        // If the run-queue has shut down, `work` (and the `sender` inside it)
+        // is dropped here; the receiver then sees a closed channel and the
+        // handle resolves to `Error::Canceled`, same as a dropped task.
+        let _ = self.tx.send(work);
+
+        TaskHandle::new(task_id, LocalResultSource::new(receiver))
+    }
+}
+
+/// Errors produced while building or running a [`DagScheduler`] batch.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DagError {
+    #[error("dependency cycle detected among submitted tasks")]
+    Cycle,
+    #[error("task panicked: {0}")]
+    Panicked(String),
+    #[error("skipped: dependency {0} failed or was never submitted")]
+    UpstreamFailed(TaskId),
+}
+
+type ErasedOutput = Box<dyn Any + Send>;
+type ErasedFuture = Pin<Box<dyn Future<Output = ErasedOutput> + Send>>;
+
+/// A [`Task`], type-erased down to its id, declared dependencies, and a
+/// boxed future producing its output as `dyn Any`, so a `DagScheduler` batch
+/// can mix tasks of different concrete types (e.g. a `generate` task
+/// followed by a `score` task with a different `Output`).
+pub struct DagTask {
+    id: TaskId,
+    dependencies: Vec<TaskId>,
+    run: Box<dyn FnOnce() -> ErasedFuture + Send>,
+}
+
+impl DagTask {
+    pub fn new<T>(task: T) -> Self
+    where
+        T: Task + 'static,
+    {
+        Self {
+            id: task.task_id(),
+            dependencies: task.dependencies(),
+            run: Box::new(move || Box::pin(async move { Box::new(task.call().await) as ErasedOutput })),
+        }
+    }
+}
+
+/// A scheduler that runs a batch of [`DagTask`]s in topological order
+/// instead of hand-sequencing `.await`s: each task declares the ids of the
+/// tasks it depends on via `Task::dependencies`, and `run` starts every task
+/// whose dependencies have all completed *successfully* as soon as they
+/// have, running up to `max_concurrency` of them at once via `join_all`. A
+/// task is run on its own `tokio::spawn`, so a panicking task surfaces as
+/// `DagError::Panicked` instead of taking the whole batch down; that failure
+/// then propagates to every (transitive) dependent, which is skipped with
+/// `DagError::UpstreamFailed` rather than run. This lets a multi-stage
+/// pipeline (`generate` -> `score` -> weight-update) be expressed as one
+/// batch instead of manually awaiting each stage.
+///
+/// Unlike [`LocalScheduler`], `DagScheduler` validates the whole batch up
+/// front: a dependency cycle is reported as [`DagError::Cycle`] before
+/// anything runs, instead of deadlocking partway through.
+///
+/// `DagScheduler` does not implement [`Scheduler`]: that trait's `submit`
+/// returns a single `Handle<T>` for one task in isolation, which has nowhere
+/// to carry a batch's dependency edges or its per-task `DagError` outcomes.
+/// Submitting one dependency-free task doesn't need dag ordering in the
+/// first place -- use [`LocalScheduler`] or [`ThrottlingScheduler`] for that
+/// and reach for `DagScheduler::run` once there's a real graph to execute.
+pub struct DagScheduler {
+    max_concurrency: usize,
+}
+
+impl DagScheduler {
+    /// `max_concurrency` bounds how many ready tasks run at once per wave;
+    /// it's clamped to at least 1.
+    pub fn new(max_concurrency: usize) -> Self {
+        Self {
+            max_concurrency: max_concurrency.max(1),
+        }
+    }
+
+    /// Run every task in `tasks` to completion, honoring declared
+    /// dependencies. Returns, per submitted task id, either its type-erased
+    /// output (downcast by the caller, who knows what concrete `Output`
+    /// type it submitted for that id) or the [`DagError`] that kept it from
+    /// completing.
+    pub async fn run(
+        &self,
+        tasks: Vec<DagTask>,
+    ) -> Result<HashMap<TaskId, Result<ErasedOutput, DagError>>, DagError> {
+        let ids: HashSet<TaskId> = tasks.iter().map(|t| t.id).collect();
+        let mut dependents: HashMap<TaskId, Vec<TaskId>> = HashMap::new();
+        let mut remaining: HashMap<TaskId, usize> = HashMap::new();
+        for task in &tasks {
+            let in_batch_deps = task.dependencies.iter().filter(|d| ids.contains(d)).count();
+            remaining.insert(task.id, in_batch_deps);
+            for dep in &task.dependencies {
+                dependents.entry(*dep).or_default().push(task.id);
+            }
+        }
+
+        Self::validate_acyclic(&remaining, &dependents)?;
+
+        let mut pending: HashMap<TaskId, DagTask> = tasks.into_iter().map(|t| (t.id, t)).collect();
+        let mut results: HashMap<TaskId, Result<ErasedOutput, DagError>> = HashMap::new();
+
+        let mut ready: Vec<TaskId> = remaining
+            .iter()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(id, _)| *id)
+            .collect();
+
+        while !ready.is_empty() {
+            let mut next_ready = Vec::new();
+
+            for chunk in ready.chunks(self.max_concurrency) {
+                let mut chunk_ids = Vec::new();
+                let mut futures = Vec::new();
+                for id in chunk {
+                    let task = pending.remove(id).expect("ready task missing from pending set");
+                    chunk_ids.push(task.id);
+                    futures.push(tokio::spawn((task.run)()));
+                }
+
+                let outcomes = join_all(futures).await;
+                for (id, outcome) in chunk_ids.into_iter().zip(outcomes) {
+                    let result = outcome.map_err(|join_err| DagError::Panicked(join_err.to_string()));
+                    let succeeded = result.is_ok();
+                    results.insert(id, result);
+
+                    for &dependent in dependents.get(&id).into_iter().flatten() {
+                        if succeeded {
+                            let deg = remaining.get_mut(&dependent).unwrap();
+                            *deg -= 1;
+                            if *deg == 0 {
+                                next_ready.push(dependent);
+                            }
+                        }
+                    }
+                }
+            }
+
+            ready = next_ready;
+        }
+
+        // Everything still in `pending` has a dependency that failed, was
+        // skipped, or was never submitted in this batch; walk the remainder
+        // repeatedly so a dependent's `UpstreamFailed` always names a
+        // dependency whose own outcome (success, failure, or skip) is
+        // already recorded.
+        loop {
+            let skippable: Vec<(TaskId, TaskId)> = pending
+                .values()
+                .filter_map(|task| {
+                    task.dependencies.iter().find_map(|dep| {
+                        let blocked = results.get(dep).is_some_and(Result::is_err)
+                            || (!pending.contains_key(dep) && !results.contains_key(dep));
+                        blocked.then_some((task.id, *dep))
+                    })
+                })
+                .collect();
+
+            if skippable.is_empty() {
+                break;
+            }
+            for (id, blocking_dep) in skippable {
+                pending.remove(&id);
+                results.insert(id, Err(DagError::UpstreamFailed(blocking_dep)));
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn validate_acyclic(
+        remaining: &HashMap<TaskId, usize>,
+        dependents: &HashMap<TaskId, Vec<TaskId>>,
+    ) -> Result<(), DagError> {
+        let mut in_degree = remaining.clone();
+        let mut ready: Vec<TaskId> = in_degree
+            .iter()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        let mut visited = 0;
+
+        while let Some(id) = ready.pop() {
+            visited += 1;
+            for &dependent in dependents.get(&id).into_iter().flatten() {
+                let deg = in_degree.get_mut(&dependent).unwrap();
+                *deg -= 1;
+                if *deg == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+
+        if visited == in_degree.len() {
+            Ok(())
+        } else {
+            Err(DagError::Cycle)
+        }
+    }
+}
+
+#[cfg(test)]
+mod scheduler_tests {
+    use super::*;
+
+    struct ValueTask<T> {
+        value: T,
+    }
+
+    impl<T: Send + 'static> Task for ValueTask<T> {
+        type Output = T;
+
+        fn call(self) -> Pin<Box<dyn Future<Output = T> + Send>> {
+            Box::pin(async move { self.value })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_local_scheduler_runs_a_submitted_task() {
+        let scheduler = LocalScheduler::new();
+        let result = scheduler.submit(ValueTask { value: 42 }).await.unwrap();
+        assert_eq!(result, 42);
+    }
+
+    #[tokio::test]
+    async fn test_throttling_scheduler_runs_every_task_batched_onto_its_run_queue() {
+        // Submits more tasks than could plausibly land in a single poll by
+        // accident, so the assertions only pass if the run-queue actually
+        // drains everything batched onto it rather than losing tasks.
+        let scheduler = ThrottlingScheduler::new(Duration::from_millis(5));
+
+        let handles: Vec<_> = (0..20)
+            .map(|i| scheduler.submit(ValueTask { value: i }))
+            .collect();
+
+        let results = join_all(handles).await;
+        for (i, result) in results.into_iter().enumerate() {
+            assert_eq!(result.unwrap(), i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod dag_tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// A task with a fixed, caller-chosen `task_id` (instead of the trait
+    /// default's fresh `Uuid::new_v4()` per call), so other tasks in the
+    /// same batch can name it as a dependency. Records its execution order
+    /// into a shared log so a test can assert the `DagScheduler` actually
+    /// serialized dependent stages instead of running everything as
+    /// independent roots.
+    struct StageTask {
+        id: TaskId,
+        dependencies: Vec<TaskId>,
+        name: &'static str,
+        log: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    impl Task for StageTask {
+        type Output = ();
+
+        fn call(self) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            Box::pin(async move {
+                self.log.lock().unwrap().push(self.name);
+            })
+        }
+
+        fn task_id(&self) -> TaskId {
+            self.id
+        }
+
+        fn dependencies(&self) -> Vec<TaskId> {
+            self.dependencies.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dag_scheduler_orders_generate_score_update_by_dependency() {
+        let generate_id = TaskId::new_v4();
+        let score_id = TaskId::new_v4();
+        let update_id = TaskId::new_v4();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let tasks = vec![
+            DagTask::new(StageTask {
+                id: update_id,
+                dependencies: vec![score_id],
+                name: "update",
+                log: log.clone(),
+            }),
+            DagTask::new(StageTask {
+                id: generate_id,
+                dependencies: vec![],
+                name: "generate",
+                log: log.clone(),
+            }),
+            DagTask::new(StageTask {
+                id: score_id,
+                dependencies: vec![generate_id],
+                name: "score",
+                log: log.clone(),
+            }),
+        ];
+
+        let results = DagScheduler::new(4).run(tasks).await.unwrap();
+
+        assert!(results[&generate_id].is_ok());
+        assert!(results[&score_id].is_ok());
+        assert!(results[&update_id].is_ok());
+
+        // Without stable, overridden `task_id`s, `score`/`update`'s declared
+        // dependencies would name ids nobody in the batch ever produces, so
+        // every task would run as an independent root in whatever order
+        // `run` happens to pick. Stable ids make the dependency edges real,
+        // which this order asserts.
+        assert_eq!(*log.lock().unwrap(), vec!["generate", "score", "update"]);
+    }
+
+    #[tokio::test]
+    async fn test_dag_scheduler_skips_dependents_of_a_failed_task() {
+        let fails_id = TaskId::new_v4();
+        let blocked_id = TaskId::new_v4();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        struct PanicTask {
+            id: TaskId,
+        }
+
+        impl Task for PanicTask {
+            type Output = ();
+
+            fn call(self) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                Box::pin(async move { panic!("boom") })
+            }
+
+            fn task_id(&self) -> TaskId {
+                self.id
+            }
+        }
+
+        let tasks = vec![
+            DagTask::new(PanicTask { id: fails_id }),
+            DagTask::new(StageTask {
+                id: blocked_id,
+                dependencies: vec![fails_id],
+                name: "blocked",
+                log: log.clone(),
+            }),
+        ];
+
+        let results = DagScheduler::new(4).run(tasks).await.unwrap();
+
+        assert!(matches!(results[&fails_id], Err(DagError::Panicked(_))));
+        assert!(matches!(
+            results[&blocked_id],
+            Err(DagError::UpstreamFailed(id)) if id == fails_id
+        ));
+        assert!(log.lock().unwrap().is_empty());
+    }
+}
@@ -1,5 +1,9 @@
 use async_trait::async_trait;
-use tokio::sync::{mpsc, oneshot};
+use std::sync::Arc;
+#[cfg(test)]
+use std::time::Duration;
+use tokio::sync::{Mutex, mpsc, oneshot};
+use tracing::Instrument;
 use uuid::Uuid;
 
 use crate::runtime::{LocalResultSource, TaskHandle, error::Error};
@@ -7,6 +11,11 @@ use crate::runtime::{LocalResultSource, TaskHandle, error::Error};
 pub type ServiceId = Uuid;
 pub type ServiceResult<T> = Result<T, Error>;
 
+/// Decides whether a `Service::call` error should be treated as fatal for the
+/// whole runner, i.e. bad enough that every other queued/future caller should
+/// be told about it too instead of getting an unrelated failure later.
+pub type FatalClassifier = Arc<dyn Fn(&Error) -> bool + Send + Sync>;
+
 /// A trait for defining a long-running, stateful service.
 ///
 /// An implementor of this trait represents an actor in the Actor Model. It owns
@@ -45,6 +54,21 @@ struct ServiceRequest {
     payload: Vec<u8>,
     /// The sender half of a one-shot channel to send the service's result back to the caller.
     respond_to: oneshot::Sender<ServiceResult<Vec<u8>>>,
+    /// Captured at `ServiceAddress::call` time, nested under the caller's
+    /// current span with the call's id and method as fields. The runner
+    /// enters it while executing `service.call`, so the work shows up in the
+    /// caller's trace no matter which task ends up running it.
+    span: tracing::Span,
+}
+
+/// Holds the fatal error a `ServiceRunner` gave up on, shared between the
+/// runner and every cloned `ServiceAddress`. Stored as `Error::Closed` so the
+/// single captured cause can be cheaply cloned (via its inner `Arc`) into the
+/// response for every pending and future caller.
+type FatalSlot = Arc<Mutex<Option<Error>>>;
+
+fn closed(cause: impl std::error::Error + Send + Sync + 'static) -> Error {
+    Error::Closed(Arc::new(cause))
 }
 
 /// A sequential runner that owns a service instance and processes requests.
@@ -58,14 +82,15 @@ pub struct ServiceRunner<S: Service> {
     id: ServiceId,
     service: S,
     rx: mpsc::Receiver<ServiceRequest>,
+    is_fatal: FatalClassifier,
+    fatal: FatalSlot,
 }
 
 impl<S: Service> ServiceRunner<S> {
     /// Spawns a new `ServiceRunner` task and returns a `ServiceAddress` for clients.
     ///
-    /// This method creates an `mpsc` channel and a new runner task on the runtime.
-    /// The runner takes ownership of the service and the receiver, while the sender
-    /// is returned to the caller inside a `ServiceAddress` struct.
+    /// No `call` error is treated as fatal; use [`ServiceRunner::spawn_with_fatal_classifier`]
+    /// to broadcast a class of errors to every queued/future caller instead.
     ///
     /// # Arguments
     ///
@@ -76,33 +101,96 @@ impl<S: Service> ServiceRunner<S> {
     ///
     /// A `ServiceAddress` used to send requests to the running service.
     pub fn spawn(service: S, capacity: usize) -> ServiceAddress
+    where
+        S: 'static,
+    {
+        Self::spawn_with_fatal_classifier(service, capacity, Arc::new(|_: &Error| false))
+    }
+
+    /// Like [`ServiceRunner::spawn`], but `is_fatal` decides which `call`
+    /// errors should be broadcast to every queued and future caller instead
+    /// of only the caller that triggered it. A runner task panic is always
+    /// treated as fatal, regardless of `is_fatal`.
+    pub fn spawn_with_fatal_classifier(
+        service: S,
+        capacity: usize,
+        is_fatal: FatalClassifier,
+    ) -> ServiceAddress
     where
         S: 'static,
     {
         let id = Uuid::new_v4();
         let (tx, rx) = mpsc::channel::<ServiceRequest>(capacity);
-        let mut runner = ServiceRunner { id, service, rx };
+        let fatal: FatalSlot = Arc::new(Mutex::new(None));
+        let mut runner = ServiceRunner {
+            id,
+            service,
+            rx,
+            is_fatal,
+            fatal: fatal.clone(),
+        };
 
-        tokio::spawn(async move {
+        let fatal_on_panic = fatal.clone();
+        let join_handle = tokio::spawn(async move {
             runner.run().await;
         });
+        tokio::spawn(async move {
+            if let Err(panic) = join_handle.await {
+                let mut slot = fatal_on_panic.lock().await;
+                if slot.is_none() {
+                    *slot = Some(closed(Error::Internal(format!(
+                        "service runner task panicked: {panic}"
+                    ))));
+                }
+            }
+        });
 
-        ServiceAddress { id, tx }
+        ServiceAddress { id, tx, fatal }
     }
 
     /// The main loop for the service runner.
     ///
     /// This async method continuously awaits a new `ServiceRequest` from the
     /// channel. When a request is received, it calls the service's method,
-    /// and sends the result back to the caller.
+    /// and sends the result back to the caller. If the result is classified
+    /// as fatal, the error is captured once and broadcast to this request,
+    /// every request still queued behind it, and (via the shared slot) every
+    /// future caller.
     async fn run(&mut self) {
         while let Some(req) = self.rx.recv().await {
-            let result = self.service.call(&req.method, req.payload).await;
+            let span = req.span.clone();
+            let result = self
+                .service
+                .call(&req.method, req.payload)
+                .instrument(span)
+                .await;
+
+            let fatal_err = match &result {
+                Err(e) if (self.is_fatal)(e) => Some(closed(e.clone())),
+                _ => None,
+            };
+
+            if let Some(fatal_err) = fatal_err {
+                *self.fatal.lock().await = Some(fatal_err.clone());
+                let _ = req.respond_to.send(Err(fatal_err.clone()));
+                self.drain_with_fatal(&fatal_err).await;
+                return;
+            }
+
             // Ignore send errors (caller may have dropped the handle)
             let _ = req.respond_to.send(result);
         }
         // When rx is closed, we exit; service is dropped here.
     }
+
+    /// Respond to every request still sitting in the channel with the
+    /// captured fatal error, instead of leaving them to time out.
+    async fn drain_with_fatal(&mut self, fatal_err: &Error) {
+        self.rx.close();
+        while let Some(req) = self.rx.recv().await {
+            let _ = req.respond_to.send(Err(fatal_err.clone()));
+        }
+    }
 }
 
 /// A public handle for sending requests to a running service.
@@ -114,6 +202,7 @@ impl<S: Service> ServiceRunner<S> {
 pub struct ServiceAddress {
     id: ServiceId,
     tx: mpsc::Sender<ServiceRequest>,
+    fatal: FatalSlot,
 }
 
 impl ServiceAddress {
@@ -126,7 +215,9 @@ impl ServiceAddress {
     ///
     /// This method is the primary way to interact with a service. It creates a
     /// new `oneshot` channel for the response and sends a `ServiceRequest`
-    /// containing the sender to the service runner.
+    /// containing the sender to the service runner. If the runner has already
+    /// recorded a fatal error, the call fails immediately with that error
+    /// instead of being enqueued.
     ///
     /// # Arguments
     ///
@@ -138,12 +229,20 @@ impl ServiceAddress {
     /// A `TaskHandle` that can be awaited to get the `Vec<u8>` response.
     pub fn call(&self, method: &str, payload: Vec<u8>) -> TaskHandle<Vec<u8>> {
         let call_id = Uuid::new_v4();
+        let parent = tracing::Span::current();
+        let span = tracing::info_span!(parent: &parent, "service_call", task_id = %call_id, method = %method);
         let (res_tx, res_rx) = oneshot::channel::<ServiceResult<Vec<u8>>>();
 
+        if let Some(fatal_err) = self.fatal.try_lock().ok().and_then(|g| g.clone()) {
+            let _ = res_tx.send(Err(fatal_err));
+            return TaskHandle::new(call_id, LocalResultSource::new(res_rx));
+        }
+
         let req = ServiceRequest {
             method: method.to_string(),
             payload,
             respond_to: res_tx,
+            span,
         };
         match self.tx.try_send(req) {
             Ok(_) => (),
@@ -160,4 +259,185 @@ impl ServiceAddress {
 
         TaskHandle::new(call_id, LocalResultSource::new(res_rx))
     }
+
+    /// Like [`ServiceAddress::call`], but instead of failing immediately when
+    /// the channel is full, awaits reserved capacity (`tx.reserve()`) so
+    /// producers apply backpressure instead of seeing `QueueFull`.
+    pub async fn call_async(&self, method: &str, payload: Vec<u8>) -> TaskHandle<Vec<u8>> {
+        let call_id = Uuid::new_v4();
+        let parent = tracing::Span::current();
+        let span = tracing::info_span!(parent: &parent, "service_call", task_id = %call_id, method = %method);
+        let (res_tx, res_rx) = oneshot::channel::<ServiceResult<Vec<u8>>>();
+
+        if let Some(fatal_err) = self.fatal.try_lock().ok().and_then(|g| g.clone()) {
+            let _ = res_tx.send(Err(fatal_err));
+            return TaskHandle::new(call_id, LocalResultSource::new(res_rx));
+        }
+
+        let req = ServiceRequest {
+            method: method.to_string(),
+            payload,
+            respond_to: res_tx,
+            span,
+        };
+
+        match self.tx.reserve().await {
+            Ok(permit) => permit.send(req),
+            Err(_) => {
+                // Recheck the fatal slot: the runner may have closed the
+                // channel because it just recorded a fatal error.
+                let err = match self.fatal.try_lock().ok().and_then(|g| g.clone()) {
+                    Some(fatal_err) => fatal_err,
+                    None => Error::ServiceUnavailable,
+                };
+                let _ = req.respond_to.send(Err(err));
+            }
+        }
+
+        TaskHandle::new(call_id, LocalResultSource::new(res_rx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoService;
+
+    #[async_trait]
+    impl Service for EchoService {
+        async fn call(&mut self, _method: &str, payload: Vec<u8>) -> ServiceResult<Vec<u8>> {
+            Ok(payload)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_returns_the_services_response() {
+        let addr = ServiceRunner::spawn(EchoService, 4);
+        let result = addr.call("echo", b"hi".to_vec()).await.unwrap();
+        assert_eq!(result, b"hi");
+    }
+
+    struct FailingService;
+
+    #[async_trait]
+    impl Service for FailingService {
+        async fn call(&mut self, _method: &str, _payload: Vec<u8>) -> ServiceResult<Vec<u8>> {
+            Err(Error::Internal("boom".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fatal_classifier_broadcasts_to_the_triggering_and_future_callers() {
+        let is_fatal: FatalClassifier = Arc::new(|e: &Error| matches!(e, Error::Internal(_)));
+        let addr = ServiceRunner::spawn_with_fatal_classifier(FailingService, 4, is_fatal);
+
+        let first = addr.call("go", vec![]).await;
+        match first {
+            Err(Error::Closed(cause)) => assert!(cause.to_string().contains("boom")),
+            other => panic!("expected a fatal Closed error, got {other:?}"),
+        }
+
+        // The runner has given up; a brand new caller sees the same captured
+        // cause instead of being enqueued behind a dead runner.
+        let later = addr.call("go", vec![]).await;
+        assert!(matches!(later, Err(Error::Closed(_))));
+    }
+
+    struct PanicService;
+
+    #[async_trait]
+    impl Service for PanicService {
+        async fn call(&mut self, _method: &str, _payload: Vec<u8>) -> ServiceResult<Vec<u8>> {
+            panic!("service call panicked")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runner_task_panic_surfaces_as_a_fatal_closed_error() {
+        let addr = ServiceRunner::spawn(PanicService, 4);
+
+        // The in-flight call's own oneshot is dropped when its task panics,
+        // so it just observes a plain cancellation; only the panic-watcher
+        // task (running concurrently) records the fatal `Closed` cause.
+        let _ = addr.call("go", vec![]).await;
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(1);
+        loop {
+            if matches!(addr.call("go", vec![]).await, Err(Error::Closed(_))) {
+                return;
+            }
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "fatal error from the panicked runner was never recorded"
+            );
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
+
+    /// Blocks `call` until externally released, so a test can hold a request
+    /// "in flight" and observe queue-full/backpressure behavior deterministically.
+    struct GatedService {
+        gate: Arc<tokio::sync::Notify>,
+    }
+
+    #[async_trait]
+    impl Service for GatedService {
+        async fn call(&mut self, _method: &str, payload: Vec<u8>) -> ServiceResult<Vec<u8>> {
+            self.gate.notified().await;
+            Ok(payload)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_fails_fast_with_queue_full_when_channel_is_saturated() {
+        let gate = Arc::new(tokio::sync::Notify::new());
+        let addr = ServiceRunner::spawn(GatedService { gate: gate.clone() }, 1);
+
+        // Occupies the runner itself (blocked on the gate); let it actually
+        // be picked up so the channel's one slot is free again.
+        let in_flight = addr.call("go", b"a".to_vec());
+        tokio::task::yield_now().await;
+
+        // Fills the channel's only slot.
+        let queued = addr.call("go", b"b".to_vec());
+
+        // The channel is now full and the runner is busy, so this must fail
+        // immediately instead of blocking.
+        let rejected = addr.call("go", b"c".to_vec()).await;
+        assert!(matches!(rejected, Err(Error::QueueFull)));
+
+        gate.notify_one();
+        gate.notify_one();
+        assert_eq!(in_flight.await.unwrap(), b"a");
+        assert_eq!(queued.await.unwrap(), b"b");
+    }
+
+    #[tokio::test]
+    async fn test_call_async_applies_backpressure_instead_of_failing_immediately() {
+        let gate = Arc::new(tokio::sync::Notify::new());
+        let addr = ServiceRunner::spawn(GatedService { gate: gate.clone() }, 1);
+
+        let in_flight = addr.call("go", b"a".to_vec());
+        tokio::task::yield_now().await;
+        let queued = addr.call("go", b"b".to_vec());
+
+        // The channel is saturated, same as the `QueueFull` test above, but
+        // `call_async` reserves capacity instead of failing immediately.
+        let addr2 = addr.clone();
+        let waiting = tokio::spawn(async move { addr2.call_async("go", b"c".to_vec()).await });
+        tokio::task::yield_now().await;
+        assert!(
+            !waiting.is_finished(),
+            "call_async should still be waiting for capacity"
+        );
+
+        gate.notify_one();
+        gate.notify_one();
+        gate.notify_one();
+
+        assert_eq!(in_flight.await.unwrap(), b"a");
+        assert_eq!(queued.await.unwrap(), b"b");
+        assert_eq!(waiting.await.unwrap().await.unwrap(), b"c");
+    }
 }
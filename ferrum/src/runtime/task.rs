@@ -1,5 +1,10 @@
 use std::future::Future;
 use std::pin::Pin;
+use uuid::Uuid;
+
+/// Identifies a [`Task`] within a [`crate::runtime::scheduler::DagScheduler`]
+/// batch, so one task's `dependencies()` can name another by id.
+pub type TaskId = Uuid;
 
 /// A trait for defining an asynchronous task that can be executed by the runtime.
 ///
@@ -40,4 +45,20 @@ pub trait Task: Send + 'static {
     /// The returned future is boxed and pinned, allowing it to be
     /// stored on the heap and ensuring its memory location is stable.
     fn call(self) -> Pin<Box<dyn Future<Output = Self::Output> + Send>>;
+
+    /// Identifies this task within a `DagScheduler` batch. The default mints
+    /// a fresh random id on every call; a task that wants a stable position
+    /// in a dependency graph (i.e. one other tasks can name via
+    /// `dependencies`) needs to override this instead.
+    fn task_id(&self) -> TaskId {
+        Uuid::new_v4()
+    }
+
+    /// Ids of tasks that must complete successfully before this one may run.
+    /// Used by [`crate::runtime::scheduler::DagScheduler`] to order
+    /// execution; schedulers with no concept of ordering (`LocalScheduler`,
+    /// `ThrottlingScheduler`) ignore it.
+    fn dependencies(&self) -> Vec<TaskId> {
+        Vec::new()
+    }
 }
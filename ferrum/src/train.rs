@@ -1,7 +1,11 @@
 // ferrum/src/train.rs
 use crate::env::{Env, VecEnv};
+use crate::inference_old::engine::InferenceEngine;
+use crate::inference_old::types::NamedWeightUpdateRequest;
 use crate::learner::Learner;
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone)]
 pub struct TrainConfig {
@@ -10,6 +14,122 @@ pub struct TrainConfig {
     pub coordinator_addr: Option<String>,
 
     pub inference_mode: InferenceMode,
+
+    /// Recurring maintenance of the inference pool (weight pushes, prefix
+    /// cache resets, sleep/wake) to run alongside the training loop.
+    pub schedule: Schedule,
+}
+
+/// What triggers a `ScheduleEntry`: either wall-clock time or a count of
+/// training steps.
+#[derive(Debug, Clone)]
+pub enum ScheduleTrigger {
+    Interval(Duration),
+    EveryNSteps(u64),
+}
+
+/// A recurring action to apply to the inference pool.
+#[derive(Debug, Clone)]
+pub enum ScheduledAction {
+    WakeUp { tags: Option<Vec<String>> },
+    Sleep { level: Option<i32> },
+    ResetPrefixCache,
+    PushWeights(NamedWeightUpdateRequest),
+}
+
+#[derive(Debug, Clone)]
+pub struct ScheduleEntry {
+    pub trigger: ScheduleTrigger,
+    pub action: ScheduledAction,
+}
+
+/// A set of recurring inference-pool maintenance entries, each fired
+/// independently when its trigger is due.
+#[derive(Debug, Clone, Default)]
+pub struct Schedule {
+    entries: Vec<ScheduleEntry>,
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a recurring action. Returns `self` for chaining.
+    pub fn with_entry(mut self, trigger: ScheduleTrigger, action: ScheduledAction) -> Self {
+        self.entries.push(ScheduleEntry { trigger, action });
+        self
+    }
+
+    /// Drive this schedule as an async loop alongside the training loop: each
+    /// entry whose trigger is due gets its engine call awaited, and its
+    /// duration recorded into `schedule_time`. `step` tracks the current
+    /// training step so `EveryNSteps` triggers can fire; callers update it
+    /// from their rollout loop.
+    ///
+    /// Runs until `step` reports `u64::MAX` (the sentinel the caller sets
+    /// when training is shutting down), checking every `poll_interval`.
+    pub async fn run<E>(
+        self,
+        engine: Arc<E>,
+        step: Arc<AtomicU64>,
+        schedule_time: Arc<Mutex<Duration>>,
+        poll_interval: Duration,
+    ) where
+        E: InferenceEngine + Send + Sync + 'static,
+    {
+        let mut last_fired: Vec<(Instant, u64)> = self
+            .entries
+            .iter()
+            .map(|_| (Instant::now(), step.load(Ordering::Relaxed)))
+            .collect();
+
+        loop {
+            let current_step = step.load(Ordering::Relaxed);
+            if current_step == u64::MAX {
+                return;
+            }
+
+            for (entry, (last_time, last_step)) in self.entries.iter().zip(last_fired.iter_mut())
+            {
+                let due = match entry.trigger {
+                    ScheduleTrigger::Interval(interval) => last_time.elapsed() >= interval,
+                    ScheduleTrigger::EveryNSteps(n) => current_step.saturating_sub(*last_step) >= n,
+                };
+                if !due {
+                    continue;
+                }
+
+                let started = Instant::now();
+                let _ = Self::fire(&engine, &entry.action).await;
+                if let Ok(mut total) = schedule_time.lock() {
+                    *total += started.elapsed();
+                }
+
+                *last_time = Instant::now();
+                *last_step = current_step;
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    async fn fire<E>(
+        engine: &E,
+        action: &ScheduledAction,
+    ) -> Result<(), crate::inference_old::InferenceError>
+    where
+        E: InferenceEngine + Send + Sync,
+    {
+        match action {
+            ScheduledAction::WakeUp { tags } => engine.wake_up(tags.clone()).await,
+            ScheduledAction::Sleep { level } => engine.sleep(*level).await,
+            ScheduledAction::ResetPrefixCache => engine.reset_prefix_cache().await,
+            ScheduledAction::PushWeights(request) => {
+                engine.update_named_weight(request.clone()).await.map(|_| ())
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +145,7 @@ impl Default for TrainConfig {
             max_episodes: None,
             coordinator_addr: None,
             inference_mode: InferenceMode::Dedicated,
+            schedule: Schedule::default(),
         }
     }
 }
@@ -35,17 +156,129 @@ pub struct TrainingStats {
     pub total_episodes: u64,
     pub training_time: Duration,
     pub final_version: u64,
+    /// Cumulative time spent awaiting `cfg.schedule`'s engine calls.
+    pub schedule_time: Duration,
 }
 
 // Start with a simple function, grow into trait later
-pub async fn train<E, L>(
+pub async fn train<E, L, I>(
     #[allow(unused_variables)] env: VecEnv<E>,
     #[allow(unused_variables)] learner: &mut L,
+    #[allow(unused_variables)] engine: Arc<I>,
     #[allow(unused_variables)] cfg: TrainConfig,
 ) -> Result<TrainingStats, Box<dyn std::error::Error>>
 where
     E: Env + Send + 'static,
     L: Learner<E::Obs, E::Act> + Send + 'static,
+    I: InferenceEngine + Send + Sync + 'static,
 {
-    todo!("Implementation coming soon!")
+    // `cfg.schedule.run` only terminates once `step` is driven to `u64::MAX`
+    // by the rollout/update loop below, and that loop doesn't exist yet.
+    // Spawning it here would leak a task that polls forever past this
+    // function returning, so it stays gated behind the loop that's actually
+    // responsible for advancing and shutting it down.
+    Err("train: rollout/update loop not implemented yet".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inference_old::InferenceError;
+    use crate::inference_old::types::{InferenceEngineInput, InferenceEngineOutput, NamedWeightUpdateRequest};
+
+    /// Engine whose `wake_up` takes long enough to be observed in
+    /// `schedule_time`, so a test can tell `Schedule::run` actually awaited
+    /// it instead of just advancing `last_fired` bookkeeping.
+    struct SlowWakeEngine;
+
+    #[async_trait::async_trait]
+    impl InferenceEngine for SlowWakeEngine {
+        fn tp_size(&self) -> usize {
+            1
+        }
+
+        async fn generate(
+            &self,
+            _input: InferenceEngineInput,
+        ) -> Result<InferenceEngineOutput, InferenceError> {
+            Ok(InferenceEngineOutput {
+                responses: vec![],
+                stop_reasons: vec![],
+                used_fallback: vec![],
+            })
+        }
+
+        async fn wake_up(&self, _tags: Option<Vec<String>>) -> Result<(), InferenceError> {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(())
+        }
+
+        async fn sleep(&self, _level: Option<i32>) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn init_weight_update_communicator(
+            &self,
+            _master_addr: String,
+            _master_port: u16,
+            _rank_offset: usize,
+            _world_size: usize,
+            _group_name: String,
+            _backend: String,
+            _override_existing: bool,
+        ) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn update_named_weight(
+            &self,
+            _request: NamedWeightUpdateRequest,
+        ) -> Result<String, InferenceError> {
+            Ok(String::new())
+        }
+
+        async fn teardown(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+
+        async fn reset_prefix_cache(&self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schedule_run_fires_due_entries_and_accumulates_schedule_time() {
+        let schedule = Schedule::new().with_entry(
+            ScheduleTrigger::EveryNSteps(1),
+            ScheduledAction::WakeUp { tags: None },
+        );
+
+        let engine = Arc::new(SlowWakeEngine);
+        let step = Arc::new(AtomicU64::new(0));
+        let schedule_time = Arc::new(Mutex::new(Duration::ZERO));
+
+        let handle = tokio::spawn(schedule.run(
+            engine,
+            step.clone(),
+            schedule_time.clone(),
+            Duration::from_millis(10),
+        ));
+
+        // Advance the step so the `EveryNSteps(1)` entry fires at least once,
+        // then set the shutdown sentinel the loop's doc comment promises.
+        step.store(1, Ordering::Relaxed);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        step.store(u64::MAX, Ordering::Relaxed);
+
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("Schedule::run did not honor the u64::MAX shutdown sentinel")
+            .expect("schedule task panicked");
+
+        let accumulated = *schedule_time.lock().unwrap();
+        assert!(
+            accumulated >= Duration::from_millis(20),
+            "expected schedule_time to include the wake_up call's latency, got {accumulated:?}"
+        );
+    }
 }
@@ -1,10 +0,0 @@
-pub mod client;
-pub mod engine;
-pub mod types;
-
-// Create a clean public interface
-pub use types::{InferenceEngineInput, InferenceEngineOutput, InferenceError, StopReason};
-
-pub use client::InferenceEngineClient;
-pub use engine::InferenceEngine;
-// pub use vllm_engine::VLLMEngine;